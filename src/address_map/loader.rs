@@ -0,0 +1,116 @@
+use super::{AddressMap, Addressable};
+use crate::cpu::mos6502::RESET_VECTOR_LL;
+
+type LoadError = String;
+
+/// Intel HEX record type for a record carrying data.
+const DATA_RECORD: u8 = 0x00;
+
+/// Intel HEX record type marking the end of the file.
+const EOF_RECORD: u8 = 0x01;
+
+/// Parses a single Intel HEX record into its type, load address and data,
+/// validating its length and checksum.
+fn parse_record(record: &str) -> Result<(u8, u16, Vec<u8>), LoadError> {
+    let digits = record
+        .strip_prefix(':')
+        .ok_or_else(|| "record is missing its start code".to_string())?;
+
+    if !digits.is_ascii() {
+        return Err("record contains non-ascii characters".to_string());
+    }
+
+    if digits.len() % 2 != 0 {
+        return Err("record contains an odd number of hex digits".to_string());
+    }
+
+    let bytes = (0..digits.len())
+        .step_by(2)
+        .map(|idx| {
+            digits
+                .get(idx..idx + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(|| format!("invalid hex byte {}", &digits[idx..idx + 2]))
+        })
+        .collect::<Result<Vec<u8>, LoadError>>()?;
+
+    // byte count, address, record type and checksum.
+    if bytes.len() < 5 {
+        return Err("record is too short".to_string());
+    }
+
+    let byte_count = bytes[0] as usize;
+    if bytes.len() != byte_count + 5 {
+        return Err(format!(
+            "record declares {} data bytes but contains {}",
+            byte_count,
+            bytes.len() - 5
+        ));
+    }
+
+    let checksum = bytes.iter().fold(0u8, |acc, &byte| acc.wrapping_add(byte));
+    if checksum != 0 {
+        let expected = bytes[..bytes.len() - 1]
+            .iter()
+            .fold(0u8, |acc, &byte| acc.wrapping_add(byte))
+            .wrapping_neg();
+        return Err(format!(
+            "checksum mismatch, expected {:#04x} found {:#04x}",
+            expected,
+            bytes[bytes.len() - 1]
+        ));
+    }
+
+    let addr = u16::from_be_bytes([bytes[1], bytes[2]]);
+    Ok((bytes[3], addr, bytes[4..4 + byte_count].to_vec()))
+}
+
+/// Loads an Intel HEX file into the address map, writing the bytes of each
+/// data record to its target address. Parsing stops at the end of file
+/// record. Malformed records, checksum failures, unsupported record types and
+/// failed writes return an error identifying the offending line.
+pub fn load_intel_hex(map: &mut AddressMap<u16, u8>, hex: &str) -> Result<(), LoadError> {
+    for (line_idx, line) in hex.lines().enumerate() {
+        let line_num = line_idx + 1;
+        let record = line.trim();
+        if record.is_empty() {
+            continue;
+        }
+
+        let (record_type, addr, data) =
+            parse_record(record).map_err(|e| format!("line {}: {}", line_num, e))?;
+
+        match record_type {
+            DATA_RECORD => map
+                .load(addr, &data)
+                .map_err(|e| format!("line {}: {}", line_num, e))?,
+            EOF_RECORD => return Ok(()),
+            other => {
+                return Err(format!(
+                    "line {}: unsupported record type {:#04x}",
+                    line_num, other
+                ))
+            }
+        }
+    }
+
+    Err("missing end of file record".to_string())
+}
+
+/// Loads a flat binary image into the address map starting at the base
+/// address, returning an error if any byte can't be written.
+pub fn load_binary(map: &mut AddressMap<u16, u8>, base: u16, data: &[u8]) -> Result<(), LoadError> {
+    map.load(base, data)
+}
+
+/// Loads a flat binary image as with `load_binary`, additionally pointing the
+/// 6502 reset vector at the base address so that the image is executed on
+/// reset.
+pub fn load_binary_with_reset_vector(
+    map: &mut AddressMap<u16, u8>,
+    base: u16,
+    data: &[u8],
+) -> Result<(), LoadError> {
+    load_binary(map, base, data)?;
+    map.write_u16(RESET_VECTOR_LL, base)
+}
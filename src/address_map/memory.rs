@@ -1,5 +1,6 @@
 use crate::address_map::Addressable;
 use std::marker::PhantomData;
+use std::ops::Range;
 
 // Represents an error that happens in interactions with memory.
 #[derive(Debug, Clone, Copy)]
@@ -103,3 +104,270 @@ impl Addressable<u16, u8> for Memory<ReadOnly, u16, u8> {
         Err("memory is read-only".to_string())
     }
 }
+
+/// Represents a segment of read-only memory, such as a cartridge ROM, backed
+/// by an owned buffer starting at a base address. Writes are always rejected.
+#[derive(Clone)]
+pub struct Rom {
+    start_address: u16,
+    inner: Vec<u8>,
+}
+
+impl Rom {
+    /// Constructs a ROM mapping the provided data beginning at the start
+    /// address.
+    pub fn new(start_address: u16, data: Vec<u8>) -> Self {
+        Self {
+            start_address,
+            inner: data,
+        }
+    }
+
+    /// Returns the number of bytes stored in the ROM.
+    pub fn size(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl Addressable<u16, u8> for Rom {
+    /// Reads a single byte at the specified address.
+    fn read(&self, addr: u16) -> u8 {
+        let addr_offset = addr - self.start_address;
+        self.inner[usize::from(addr_offset)]
+    }
+
+    /// write returns an error signifying that the ROM can't be modified.
+    fn write(&mut self, addr: u16, _: u8) -> Result<u8, String> {
+        Err(format!("rom is read-only, rejected write to {:#06x}", addr))
+    }
+}
+
+/// Represents a single addressable segment of RAM in which only a set of
+/// sub-ranges are writable, modeling a write-protect register. Writable
+/// ranges are provided as offsets from the start address. A cpu store to a
+/// protected offset surfaces as a `StepError::Write`.
+#[derive(Clone)]
+pub struct ProtectedMemory {
+    start_address: u16,
+    writable: Vec<Range<u16>>,
+    inner: Vec<u8>,
+}
+
+impl ProtectedMemory {
+    /// Allocates a new protected memory module of `size` bytes starting at
+    /// `start_address`, allowing writes only to the `writable` offsets.
+    pub fn new(start_address: u16, size: usize, writable: Vec<Range<u16>>) -> Self {
+        Self {
+            start_address,
+            writable,
+            inner: vec![0; size],
+        }
+    }
+
+    /// Load data into memory, bypassing write protection, returning an
+    /// instance of ProtectedMemory with the newly loaded dataset.
+    pub fn load(self, data: Vec<u8>) -> Self {
+        Self {
+            start_address: self.start_address,
+            writable: self.writable,
+            inner: data,
+        }
+    }
+}
+
+impl Addressable<u16, u8> for ProtectedMemory {
+    /// Reads a single byte at the specified address.
+    fn read(&self, addr: u16) -> u8 {
+        let addr_offset = addr - self.start_address;
+        self.inner[usize::from(addr_offset)]
+    }
+
+    /// Assigns a single value to an address in memory, returning an error if
+    /// the address falls outside all writable ranges.
+    fn write(&mut self, addr: u16, value: u8) -> Result<u8, String> {
+        let addr_offset = addr - self.start_address;
+        if self.writable.iter().any(|r| r.contains(&addr_offset)) {
+            self.inner[usize::from(addr_offset)] = value;
+            Ok(value)
+        } else {
+            Err(format!(
+                "memory offset {:#06x} is write-protected",
+                addr_offset
+            ))
+        }
+    }
+}
+
+/// Represents a bank-switched segment of RAM in which multiple equally sized
+/// banks share a single address range. Reads and writes are routed to the
+/// currently selected bank.
+///
+/// Once registered in an address map, banks can be switched through an
+/// optional select register. Writing a bank index to the register selects
+/// that bank and reading it returns the active index. The register takes
+/// precedence over any bank byte at the same address, and the registered
+/// range must include it.
+#[derive(Clone)]
+pub struct BankedMemory {
+    start_address: u16,
+    active: usize,
+    select_register: Option<u16>,
+    banks: Vec<Vec<u8>>,
+}
+
+impl BankedMemory {
+    /// Allocates `bank_count` zeroed banks of `bank_size` bytes starting at
+    /// `start_address`, with the first bank selected.
+    pub fn new(start_address: u16, bank_size: usize, bank_count: usize) -> Self {
+        Self {
+            start_address,
+            active: 0,
+            select_register: None,
+            banks: vec![vec![0; bank_size]; bank_count],
+        }
+    }
+
+    /// Returns an instance of BankedMemory that selects banks through writes
+    /// to the provided address.
+    pub fn with_select_register(mut self, addr: u16) -> Self {
+        self.select_register = Some(addr);
+        self
+    }
+
+    /// Load data into the bank at the specified index, returning an instance
+    /// of BankedMemory with the newly loaded dataset. An error is returned if
+    /// the index doesn't correspond to a bank or the data doesn't match the
+    /// bank size.
+    pub fn load_bank(mut self, index: usize, data: Vec<u8>) -> Result<Self, String> {
+        let bank_count = self.banks.len();
+        let bank = self.banks.get_mut(index).ok_or_else(|| {
+            format!(
+                "bank {} out of range, {} banks available",
+                index, bank_count
+            )
+        })?;
+
+        if data.len() != bank.len() {
+            return Err(format!(
+                "bank data is {} bytes, expected {}",
+                data.len(),
+                bank.len()
+            ));
+        }
+
+        *bank = data;
+        Ok(self)
+    }
+
+    /// Selects the bank that subsequent reads and writes are routed to,
+    /// returning an error if the index doesn't correspond to a bank.
+    pub fn select_bank(&mut self, index: usize) -> Result<(), String> {
+        if index < self.banks.len() {
+            self.active = index;
+            Ok(())
+        } else {
+            Err(format!(
+                "bank {} out of range, {} banks available",
+                index,
+                self.banks.len()
+            ))
+        }
+    }
+
+    /// Returns the index of the currently selected bank.
+    pub fn active_bank(&self) -> usize {
+        self.active
+    }
+}
+
+impl Addressable<u16, u8> for BankedMemory {
+    /// Reads a single byte at the specified address from the active bank, or
+    /// the active bank index from the select register.
+    fn read(&self, addr: u16) -> u8 {
+        if self.select_register == Some(addr) {
+            return self.active as u8;
+        }
+
+        let addr_offset = addr - self.start_address;
+        self.banks[self.active][usize::from(addr_offset)]
+    }
+
+    /// Assigns a single value to an address in the active bank, or selects
+    /// the bank with the value's index on a write to the select register.
+    fn write(&mut self, addr: u16, value: u8) -> Result<u8, String> {
+        if self.select_register == Some(addr) {
+            return self.select_bank(usize::from(value)).map(|_| value);
+        }
+
+        let addr_offset = addr - self.start_address;
+        self.banks[self.active][usize::from(addr_offset)] = value;
+        Ok(value)
+    }
+}
+
+/// Represents a flat 64KiB segment of RAM covering the entire 16-bit address
+/// space, stored inline rather than split across multiple address spaces.
+#[derive(Clone)]
+pub struct FlatMemory {
+    inner: [u8; 0x10000],
+}
+
+impl FlatMemory {
+    /// Allocates a new zeroed flat memory module.
+    pub fn new() -> Self {
+        Self {
+            inner: [0; 0x10000],
+        }
+    }
+
+    /// Load data into memory starting at the origin address, returning an
+    /// instance of FlatMemory with the newly loaded dataset. An error is
+    /// returned if the data runs past the end of the address space.
+    pub fn load(mut self, origin: u16, data: &[u8]) -> Result<Self, String> {
+        let start = usize::from(origin);
+        match self.inner.get_mut(start..start + data.len()) {
+            Some(dest) => {
+                dest.copy_from_slice(data);
+                Ok(self)
+            }
+            None => Err(format!(
+                "{} bytes at {:#06x} overrun the address space",
+                data.len(),
+                origin
+            )),
+        }
+    }
+
+    /// Dump converts the current state of memory into a corresponding Vec<u8>.
+    pub fn dump(&self) -> Vec<u8> {
+        self.inner.to_vec()
+    }
+}
+
+impl std::fmt::Debug for FlatMemory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FlatMemory")
+            .field("len", &self.inner.len())
+            .finish()
+    }
+}
+
+impl Default for FlatMemory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Addressable<u16, u8> for FlatMemory {
+    /// Reads a single byte at the specified address.
+    fn read(&self, addr: u16) -> u8 {
+        self.inner[usize::from(addr)]
+    }
+
+    /// Assigns a single value to an address in memory. As all addresses are
+    /// backed, this never fails.
+    fn write(&mut self, addr: u16, value: u8) -> Result<u8, String> {
+        self.inner[usize::from(addr)] = value;
+        Ok(value)
+    }
+}
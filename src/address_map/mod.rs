@@ -1,7 +1,16 @@
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::fmt;
-use std::{cmp::Eq, fmt::Debug, hash::Hash, ops::RangeInclusive};
+use std::rc::Rc;
+use std::{
+    cmp::Eq,
+    convert::TryFrom,
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, RangeInclusive, Sub},
+};
 
+pub mod loader;
 pub mod memory;
 pub mod page;
 
@@ -11,6 +20,20 @@ mod tests;
 type WriteError = String;
 type RegistrationError = String;
 
+/// A registered address range and the addressable it is mapped to.
+type AddressSpace<O, V> = (RangeInclusive<O>, Handler<O, V>);
+
+/// A single addressable mapped into several address ranges, such as the parts
+/// of a space split by `register_override`.
+type SharedHandler<O, V> = Rc<RefCell<Box<dyn Addressable<O, V>>>>;
+
+/// A registered address range and the accesses recorded against it.
+type RangeAccessStats<O> = (RangeInclusive<O>, AccessStats);
+
+/// A watched address range and the callback invoked with the address and
+/// value of each successful write that lands in it.
+type WriteWatch<O, V> = (RangeInclusive<O>, Rc<dyn Fn(O, V)>);
+
 /// SafeAddressable represents an implementation of Addressable that will
 /// _NEVER_ fail a write. An example would be a ring buffer that will always
 /// wrap its address space.
@@ -39,6 +62,34 @@ where
 {
     fn read(&self, offset: O) -> V;
     fn write(&mut self, offset: O, data: V) -> Result<V, WriteError>;
+
+    /// Reads a little-endian 16-bit value from the offset and the offset
+    /// following it, wrapping at the end of the address space.
+    fn read_u16(&self, offset: O) -> u16
+    where
+        O: From<u16> + Into<u16>,
+        V: Into<u8>,
+    {
+        let offset: u16 = offset.into();
+        u16::from_le_bytes([
+            self.read(O::from(offset)).into(),
+            self.read(O::from(offset.wrapping_add(1))).into(),
+        ])
+    }
+
+    /// Writes a 16-bit value, low byte first, to the offset and the offset
+    /// following it, wrapping at the end of the address space.
+    fn write_u16(&mut self, offset: O, data: u16) -> Result<(), WriteError>
+    where
+        O: From<u16> + Into<u16>,
+        V: From<u8>,
+    {
+        let offset: u16 = offset.into();
+        let [lsb, msb] = data.to_le_bytes();
+        self.write(O::from(offset), V::from(lsb))?;
+        self.write(O::from(offset.wrapping_add(1)), V::from(msb))?;
+        Ok(())
+    }
 }
 
 impl<O, V> Clone for Box<dyn Addressable<O, V>>
@@ -65,16 +116,128 @@ where
     }
 }
 
+/// Handler is the addressable backing a registered address space, either
+/// owned outright or shared with the other spaces it is mapped into.
+enum Handler<O, V> {
+    Owned(Box<dyn Addressable<O, V>>),
+    Shared(SharedHandler<O, V>),
+}
+
+impl<O, V> Handler<O, V>
+where
+    O: 'static + Into<usize> + Debug + Clone + Copy,
+    V: 'static + Debug + Clone + Copy,
+{
+    fn read(&self, offset: O) -> V {
+        match self {
+            Handler::Owned(a) => a.read(offset),
+            Handler::Shared(a) => a.borrow().read(offset),
+        }
+    }
+
+    fn write(&mut self, offset: O, data: V) -> Result<V, WriteError> {
+        match self {
+            Handler::Owned(a) => a.write(offset, data),
+            Handler::Shared(a) => a.borrow_mut().write(offset, data),
+        }
+    }
+
+    fn into_addressable(self) -> Box<dyn Addressable<O, V>> {
+        match self {
+            Handler::Owned(a) => a,
+            Handler::Shared(a) => Box::new(SharedAddressable { inner: a }),
+        }
+    }
+}
+
+/// SharedAddressable wraps a shared handler that has been deregistered from
+/// one of the spaces it is mapped into, continuing to share its state with
+/// the spaces that remain.
+#[derive(Clone)]
+struct SharedAddressable<O, V> {
+    inner: SharedHandler<O, V>,
+}
+
+impl<O, V> Addressable<O, V> for SharedAddressable<O, V>
+where
+    O: 'static + Into<usize> + Debug + Clone + Copy,
+    V: 'static + Debug + Clone + Copy,
+{
+    fn read(&self, offset: O) -> V {
+        self.inner.borrow().read(offset)
+    }
+
+    fn write(&mut self, offset: O, data: V) -> Result<V, WriteError> {
+        self.inner.borrow_mut().write(offset, data)
+    }
+}
+
+/// AccessStats represents the number of reads and writes made against a
+/// registered address range.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AccessStats {
+    pub reads: usize,
+    pub writes: usize,
+}
+
 /// AddressMap contains a mapping of address spaces to corresponding addressable
 /// IO with the purpose of acting as an address map. This time is, additionally,
 /// an implementation Addressable allowing all other components to interact with
 /// it as if it were a bus.
-#[derive(Default, Clone)]
+///
+/// Address spaces are keyed by their start address, and any enumeration of
+/// them is guaranteed to be in ascending order by start address regardless of
+/// the order they were registered in.
+#[derive(Default)]
 pub struct AddressMap<O, V>
 where
     O: Into<usize> + Debug + Clone + Copy,
 {
-    inner: HashMap<RangeInclusive<O>, Box<dyn Addressable<O, V>>>,
+    inner: BTreeMap<O, AddressSpace<O, V>>,
+    access_stats: Option<RefCell<BTreeMap<O, RangeAccessStats<O>>>>,
+    default_read_value: V,
+    watches: Vec<WriteWatch<O, V>>,
+}
+
+impl<O, V> Clone for AddressMap<O, V>
+where
+    O: Into<usize> + Ord + Debug + Clone + Copy,
+    V: Clone,
+{
+    /// Clones each addressable in the map. A shared addressable is cloned
+    /// once, with every space it was mapped into relinked to the copy, so
+    /// that the clone shares no state with the original.
+    fn clone(&self) -> Self {
+        let mut relinked: Vec<(SharedHandler<O, V>, SharedHandler<O, V>)> = Vec::new();
+        let inner = self
+            .inner
+            .iter()
+            .map(|(start, (range, handler))| {
+                let handler = match handler {
+                    Handler::Owned(a) => Handler::Owned(a.clone()),
+                    Handler::Shared(a) => {
+                        let copy = match relinked.iter().find(|(orig, _)| Rc::ptr_eq(orig, a)) {
+                            Some((_, copy)) => copy.clone(),
+                            None => {
+                                let copy = Rc::new(RefCell::new(a.borrow().clone()));
+                                relinked.push((a.clone(), copy.clone()));
+                                copy
+                            }
+                        };
+                        Handler::Shared(copy)
+                    }
+                };
+                (*start, (range.clone(), handler))
+            })
+            .collect();
+
+        AddressMap {
+            inner,
+            access_stats: self.access_stats.clone(),
+            default_read_value: self.default_read_value.clone(),
+            watches: self.watches.clone(),
+        }
+    }
 }
 
 impl<O, V> fmt::Debug for AddressMap<O, V>
@@ -82,18 +245,83 @@ where
     O: Into<usize> + Debug + Clone + Copy,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let keys: Vec<&RangeInclusive<O>> = self.inner.keys().collect();
+        let keys: Vec<&RangeInclusive<O>> = self.inner.values().map(|(range, _)| range).collect();
         write!(f, "AddressMap {:?}", keys)
     }
 }
 
 impl<O, V> AddressMap<O, V>
 where
-    O: Into<usize> + Hash + PartialOrd + Eq + Debug + Clone + Copy,
+    O: Into<usize> + Hash + Ord + Debug + Clone + Copy,
 {
-    pub fn new() -> Self {
+    pub fn new() -> Self
+    where
+        V: Default,
+    {
         AddressMap {
-            inner: HashMap::default(),
+            inner: BTreeMap::default(),
+            access_stats: None,
+            default_read_value: V::default(),
+            watches: Vec::new(),
+        }
+    }
+
+    /// Sets the value returned by reads of unmapped addresses, such as `0xff`
+    /// for a floating bus. This defaults to `V::default()`.
+    pub fn with_default_read_value(mut self, value: V) -> Self {
+        self.default_read_value = value;
+        self
+    }
+
+    /// Returns an iterator over each registered address range, in ascending
+    /// order by start address.
+    pub fn regions(&self) -> impl Iterator<Item = &RangeInclusive<O>> {
+        self.inner.values().map(|(range, _)| range)
+    }
+
+    /// Enables or disables the tracking of per-range read and write counts.
+    /// Disabling tracking discards any previously recorded counts.
+    pub fn with_access_stats(mut self, enabled: bool) -> Self {
+        self.access_stats = if enabled {
+            Some(RefCell::new(BTreeMap::default()))
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Returns the read and write counts recorded for each registered range
+    /// that has been accessed, in ascending order by start address. Writes
+    /// rejected by the addressable aren't counted. This is empty if tracking
+    /// is not enabled.
+    pub fn access_stats(&self) -> Vec<RangeAccessStats<O>> {
+        self.access_stats
+            .as_ref()
+            .map(|stats| stats.borrow().values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// watch registers a callback that is invoked with the address and value
+    /// of every successful write landing in the range. Reads never trigger a
+    /// callback, and clones of the map share its registered callbacks.
+    pub fn watch<F>(&mut self, range: RangeInclusive<O>, callback: F)
+    where
+        F: Fn(O, V) + 'static,
+    {
+        self.watches.push((range, Rc::new(callback)));
+    }
+
+    /// Records an access against a range if tracking is enabled.
+    fn record_access<F>(&self, range: &RangeInclusive<O>, f: F)
+    where
+        F: FnOnce(&mut AccessStats),
+    {
+        if let Some(stats) = &self.access_stats {
+            let mut stats = stats.borrow_mut();
+            let (_, range_stats) = stats
+                .entry(*range.start())
+                .or_insert_with(|| (range.clone(), AccessStats::default()));
+            f(range_stats)
         }
     }
 
@@ -105,9 +333,10 @@ where
         addr_space: Box<dyn Addressable<O, V>>,
     ) -> Result<AddressMap<O, V>, RegistrationError> {
         self.inner
-            .keys()
-            .map(|key| {
-                if key.contains(range.start()) || key.contains(range.end()) {
+            .values()
+            .map(|(key, _)| {
+                // inclusive ranges overlap when each starts before the other ends.
+                if range.start() <= key.end() && key.start() <= range.end() {
                     Err(format!(
                         "address space {:?} overlaps with {:?}",
                         &range, &key
@@ -119,40 +348,147 @@ where
             .collect::<Result<Vec<()>, RegistrationError>>()
             .map_err(|e| e)
             .map(|_| {
-                self.inner.insert(range, addr_space);
+                self.inner
+                    .insert(*range.start(), (range, Handler::Owned(addr_space)));
                 self
             })
     }
+
+    /// load writes a contiguous slice of values beginning at the start
+    /// address. Loading stops at, and returns, the first write that fails,
+    /// such as one to an unallocated or read-only address or one past the end
+    /// of the address space.
+    pub fn load(&mut self, start: O, values: &[V]) -> Result<(), WriteError>
+    where
+        O: 'static + TryFrom<usize>,
+        V: 'static + Default + Debug + Clone + Copy,
+    {
+        let start: usize = start.into();
+        for (offset, &value) in values.iter().enumerate() {
+            let addr = O::try_from(start + offset)
+                .map_err(|_| format!("address {:#x} is out of range", start + offset))?;
+            self.write(addr, value)?;
+        }
+
+        Ok(())
+    }
+
+    /// deregister removes the address space registered at exactly the provided
+    /// range, returning its addressable. Subsequent accesses to the range
+    /// behave as unallocated. A range that doesn't exactly match a registered
+    /// range, including one that only overlaps it, leaves the map unchanged
+    /// and returns `None`.
+    pub fn deregister(&mut self, range: &RangeInclusive<O>) -> Option<Box<dyn Addressable<O, V>>>
+    where
+        O: 'static,
+        V: 'static + Debug + Clone + Copy,
+    {
+        match self.inner.get(range.start()) {
+            Some((key, _)) if key == range => {
+                if let Some(stats) = &self.access_stats {
+                    stats.borrow_mut().remove(range.start());
+                }
+                self.inner
+                    .remove(range.start())
+                    .map(|(_, a)| a.into_addressable())
+            }
+            _ => None,
+        }
+    }
+
+    /// register_override registers an address space that may be carved out
+    /// of a single existing address space that encloses it. The enclosing
+    /// space is split around the new range, with the parts on either side
+    /// remaining mapped to the original addressable, so that the new range
+    /// takes precedence. When both parts remain they share the one
+    /// addressable. A range that isn't enclosed by an existing address space
+    /// is registered as with `register`.
+    pub fn register_override(
+        mut self,
+        range: RangeInclusive<O>,
+        addr_space: Box<dyn Addressable<O, V>>,
+    ) -> Result<AddressMap<O, V>, RegistrationError>
+    where
+        O: 'static + Add<Output = O> + Sub<Output = O> + From<u8>,
+        V: 'static + Debug + Clone + Copy,
+    {
+        let enclosing = self
+            .inner
+            .values()
+            .map(|(key, _)| key)
+            .find(|key| key.contains(range.start()) && key.contains(range.end()))
+            .cloned();
+        let enclosing = match enclosing {
+            Some(enclosing) => enclosing,
+            None => return self.register(range, addr_space),
+        };
+
+        // the enclosing space is guaranteed to be registered.
+        let (_, outer) = self.inner.remove(enclosing.start()).unwrap();
+        let left = (enclosing.start() < range.start())
+            .then(|| *enclosing.start()..=(*range.start() - O::from(1)));
+        let right =
+            (range.end() < enclosing.end()).then(|| (*range.end() + O::from(1))..=*enclosing.end());
+
+        match (left, right) {
+            (Some(left), Some(right)) => {
+                let shared = Rc::new(RefCell::new(outer.into_addressable()));
+                self.inner
+                    .insert(*left.start(), (left, Handler::Shared(shared.clone())));
+                self.inner
+                    .insert(*right.start(), (right, Handler::Shared(shared)));
+            }
+            (Some(remnant), None) | (None, Some(remnant)) => {
+                self.inner.insert(*remnant.start(), (remnant, outer));
+            }
+            (None, None) => (),
+        }
+        self.inner
+            .insert(*range.start(), (range, Handler::Owned(addr_space)));
+
+        Ok(self)
+    }
 }
 
 impl<O, V> Addressable<O, V> for AddressMap<O, V>
 where
-    O: 'static + Into<usize> + Hash + PartialOrd + Eq + Debug + Clone + Copy,
+    O: 'static + Into<usize> + Hash + Ord + Debug + Clone + Copy,
     V: 'static + Default + Debug + Clone + Copy,
 {
     /// Reads a single byte at the specified address
     fn read(&self, addr: O) -> V {
+        // the only space that can contain addr is the last to start at or
+        // before it.
         self.inner
-            .keys()
-            .filter(|key| key.contains(&addr))
-            .map(|r| self.inner.get(r))
-            .flatten()
-            .next()
-            .map_or(<V>::default(), |a| a.read(addr))
+            .range(..=addr)
+            .next_back()
+            .map(|(_, (range, a))| (range, a))
+            .filter(|(range, _)| range.contains(&addr))
+            .map_or(self.default_read_value, |(range, a)| {
+                self.record_access(range, |stats| stats.reads += 1);
+                a.read(addr)
+            })
     }
 
     /// Write assigns a single value to an address in memory
     fn write(&mut self, addr: O, value: V) -> Result<V, String> {
         let range = self
             .inner
-            .keys()
-            .cloned()
-            .find(|key| key.contains(&addr))
+            .range(..=addr)
+            .next_back()
+            .map(|(_, (range, _))| range.clone())
+            .filter(|range| range.contains(&addr))
             .ok_or(format!("address space {:?} unallocated", addr))?;
-        let am = self
+        let (_, am) = self
             .inner
-            .get_mut(&range)
+            .get_mut(range.start())
             .ok_or(format!("address space {:?} unallocated", addr))?;
-        am.write(addr, value)
+        let value = am.write(addr, value)?;
+        self.record_access(&range, |stats| stats.writes += 1);
+        self.watches
+            .iter()
+            .filter(|(range, _)| range.contains(&addr))
+            .for_each(|(_, callback)| callback(addr, value));
+        Ok(value)
     }
 }
@@ -0,0 +1,108 @@
+use crate::address_map::{
+    loader::{load_binary, load_binary_with_reset_vector, load_intel_hex},
+    memory::{Memory, ReadWrite},
+    AddressMap, Addressable,
+};
+
+fn ram_address_map() -> AddressMap<u16, u8> {
+    AddressMap::new()
+        .register(
+            0..=std::u16::MAX,
+            Box::new(Memory::<ReadWrite, u16, u8>::new(0, std::u16::MAX)),
+        )
+        .unwrap()
+}
+
+#[test]
+fn should_load_each_data_record_of_an_intel_hex_file() {
+    let mut am = ram_address_map();
+    let hex = "\
+:046000000102030492
+:03FFFC0000600A98
+:00000001FF
+";
+
+    assert_eq!(Ok(()), load_intel_hex(&mut am, hex));
+    assert_eq!(
+        vec![0x01, 0x02, 0x03, 0x04],
+        (0x6000..=0x6003)
+            .map(|addr| am.read(addr))
+            .collect::<Vec<u8>>()
+    );
+    assert_eq!(
+        vec![0x00, 0x60, 0x0a],
+        (0xfffc..=0xfffe)
+            .map(|addr| am.read(addr))
+            .collect::<Vec<u8>>()
+    );
+}
+
+#[test]
+fn should_reject_an_intel_hex_record_with_an_invalid_checksum() {
+    let mut am = ram_address_map();
+    let hex = "\
+:046000000102030492
+:0460040005060708FF
+:00000001FF
+";
+
+    assert_eq!(
+        Err("line 2: checksum mismatch, expected 0x7e found 0xff".to_string()),
+        load_intel_hex(&mut am, hex)
+    );
+}
+
+#[test]
+fn should_reject_malformed_intel_hex_records() {
+    let mut am = ram_address_map();
+
+    assert_eq!(
+        Err("line 1: record is missing its start code".to_string()),
+        load_intel_hex(&mut am, "0460000001020304F2")
+    );
+    assert_eq!(
+        Err("line 1: record declares 4 data bytes but contains 3".to_string()),
+        load_intel_hex(&mut am, ":04600000010203F5")
+    );
+    assert_eq!(
+        Err("missing end of file record".to_string()),
+        load_intel_hex(&mut am, ":046000000102030492")
+    );
+}
+
+#[test]
+fn should_reject_intel_hex_records_containing_non_ascii_characters() {
+    let mut am = ram_address_map();
+
+    assert_eq!(
+        Err("line 1: record contains non-ascii characters".to_string()),
+        load_intel_hex(&mut am, ":0é000")
+    );
+}
+
+#[test]
+fn should_load_a_binary_image_at_the_base_address() {
+    let mut am = ram_address_map();
+
+    assert_eq!(Ok(()), load_binary(&mut am, 0x8000, &[0xa9, 0xff, 0xea]));
+    assert_eq!(
+        vec![0xa9, 0xff, 0xea],
+        (0x8000..=0x8002)
+            .map(|addr| am.read(addr))
+            .collect::<Vec<u8>>()
+    );
+    // the reset vector is left untouched.
+    assert_eq!(0x0000, am.read_u16(0xfffc));
+}
+
+#[test]
+fn should_point_the_reset_vector_at_a_loaded_binary_image() {
+    use crate::cpu::{mos6502::Mos6502, register::Register};
+
+    let mut am = ram_address_map();
+    load_binary_with_reset_vector(&mut am, 0x8000, &[0xa9, 0xff, 0xea]).unwrap();
+
+    let cpu = Mos6502::with_addressmap(am).reset().unwrap();
+
+    assert_eq!(0x8000, cpu.pc.read());
+}
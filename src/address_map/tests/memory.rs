@@ -1,5 +1,5 @@
 use crate::address_map::{
-    memory::{Memory, ReadOnly, ReadWrite},
+    memory::{BankedMemory, Memory, ProtectedMemory, ReadOnly, ReadWrite, Rom},
     Addressable,
 };
 
@@ -64,3 +64,115 @@ fn should_correctly_calculate_offsets() {
     assert_eq!(0xff, first_value);
     assert_eq!(0x8000, data.len());
 }
+
+#[test]
+fn should_only_write_to_writable_ranges_of_protected_memory() {
+    // lower half writable, upper half locked.
+    let mut mem = ProtectedMemory::new(0x8000, 0x100, vec![0x00..0x80]).load(vec![0xaa; 0x100]);
+
+    assert_eq!(Ok(0xff), mem.write(0x8010, 0xff));
+    assert_eq!(
+        Err("memory offset 0x0090 is write-protected".to_string()),
+        mem.write(0x8090, 0xff)
+    );
+
+    assert_eq!(0xff, mem.read(0x8010));
+    assert_eq!(0xaa, mem.read(0x8090));
+}
+
+#[test]
+fn should_read_stored_bytes_from_rom() {
+    let rom = Rom::new(0x8000, vec![0xa9, 0x55, 0xea]);
+
+    assert_eq!(3, rom.size());
+    assert_eq!(0xa9, rom.read(0x8000));
+    assert_eq!(0xea, rom.read(0x8002));
+}
+
+#[test]
+fn should_reject_writes_to_rom() {
+    let mut rom = Rom::new(0x8000, vec![0xa9, 0x55, 0xea]);
+
+    assert_eq!(
+        Err("rom is read-only, rejected write to 0x8001".to_string()),
+        rom.write(0x8001, 0xff)
+    );
+    assert_eq!(0x55, rom.read(0x8001));
+}
+
+#[test]
+fn should_route_reads_and_writes_to_the_selected_bank() {
+    let mut mem = BankedMemory::new(0x8000, 0x4000, 2)
+        .load_bank(0, vec![0x11; 0x4000])
+        .and_then(|mem| mem.load_bank(1, vec![0x22; 0x4000]))
+        .unwrap();
+
+    assert_eq!(0x11, mem.read(0x8000));
+
+    mem.select_bank(1).unwrap();
+    assert_eq!(1, mem.active_bank());
+    assert_eq!(0x22, mem.read(0x8000));
+
+    mem.write(0x8000, 0xff).unwrap();
+    mem.select_bank(0).unwrap();
+    assert_eq!(0x11, mem.read(0x8000));
+    mem.select_bank(1).unwrap();
+    assert_eq!(0xff, mem.read(0x8000));
+}
+
+#[test]
+fn should_reject_selecting_an_out_of_range_bank() {
+    let mut mem = BankedMemory::new(0x8000, 0x4000, 2);
+
+    assert_eq!(
+        Err("bank 2 out of range, 2 banks available".to_string()),
+        mem.select_bank(2)
+    );
+    assert_eq!(0, mem.active_bank());
+}
+
+#[test]
+fn should_switch_banks_of_registered_memory_through_its_select_register() {
+    use crate::address_map::AddressMap;
+
+    let mem = BankedMemory::new(0x8000, 0x4000, 2)
+        .load_bank(0, vec![0x11; 0x4000])
+        .and_then(|mem| mem.load_bank(1, vec![0x22; 0x4000]))
+        .unwrap()
+        .with_select_register(0xc000);
+    let mut am = AddressMap::<u16, u8>::new()
+        .register(0x8000..=0xc000, Box::new(mem))
+        .unwrap();
+
+    assert_eq!(0x11, am.read(0x8000));
+    am.write(0xc000, 0x01).unwrap();
+    assert_eq!(0x01, am.read(0xc000));
+    assert_eq!(0x22, am.read(0x8000));
+
+    // a clone of the map keeps its own selection.
+    let mut cloned = am.clone();
+    cloned.write(0xc000, 0x00).unwrap();
+    assert_eq!(0x11, cloned.read(0x8000));
+    assert_eq!(0x22, am.read(0x8000));
+
+    assert_eq!(
+        Err("bank 2 out of range, 2 banks available".to_string()),
+        am.write(0xc000, 0x02)
+    );
+}
+
+#[test]
+fn should_reject_loading_an_invalid_bank() {
+    assert_eq!(
+        Err("bank 2 out of range, 2 banks available".to_string()),
+        BankedMemory::new(0x8000, 0x4000, 2)
+            .load_bank(2, vec![0x11; 0x4000])
+            .map(|mem| mem.active_bank())
+    );
+    assert_eq!(
+        Err("bank data is 16 bytes, expected 16384".to_string()),
+        BankedMemory::new(0x8000, 0x4000, 2)
+            .load_bank(0, vec![0x11; 0x10])
+            .map(|mem| mem.active_bank())
+    );
+}
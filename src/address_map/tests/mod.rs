@@ -3,6 +3,7 @@ use crate::address_map::{
     Addressable,
 };
 
+mod loader;
 mod memory;
 
 macro_rules! u16_address_map {
@@ -44,6 +45,53 @@ fn should_fail_when_registering_overlapping_address_space() {
         .is_err());
 }
 
+#[test]
+fn should_fail_when_registering_any_overlapping_address_space() {
+    let cases = [
+        (0x1000..=0x4fff, "enclosing"),
+        (0x2800..=0x2fff, "enclosed"),
+        (0x1000..=0x2000, "left-partial"),
+        (0x3fff..=0x4fff, "right-partial"),
+    ];
+
+    for (range, case) in cases.iter() {
+        let am = u16_address_map!(
+            0x2000..=0x3fff,
+            Memory::<ReadOnly, u16, u8>::new(0x2000, 0x3fff)
+        )
+        .unwrap();
+        let (start, end) = (*range.start(), *range.end());
+        assert!(
+            am.register(
+                range.clone(),
+                Box::new(Memory::<ReadOnly, u16, u8>::new(start, end))
+            )
+            .is_err(),
+            "{}",
+            case
+        );
+    }
+}
+
+#[test]
+fn should_register_adjacent_address_spaces() {
+    let am = u16_address_map!(
+        0x2000..=0x3fff,
+        Memory::<ReadOnly, u16, u8>::new(0x2000, 0x3fff)
+    )
+    .unwrap();
+    assert!(am
+        .register(
+            0x4000..=0x4fff,
+            Box::new(Memory::<ReadOnly, u16, u8>::new(0x4000, 0x4fff))
+        )
+        .and_then(|am| am.register(
+            0x1000..=0x1fff,
+            Box::new(Memory::<ReadOnly, u16, u8>::new(0x1000, 0x1fff))
+        ))
+        .is_ok());
+}
+
 #[test]
 fn should_read_valid_memory() {
     let am = u16_address_map!().unwrap();
@@ -56,3 +104,343 @@ fn should_write_valid_memory() {
     assert!(am.write(0xaaaa, 0xff).is_ok());
     assert_eq!(0xff, am.read(0xaaaa));
 }
+
+#[test]
+fn should_track_access_stats_per_range_when_enabled() {
+    use crate::address_map::{memory::ReadWrite, AccessStats, AddressMap};
+
+    let mut am = AddressMap::<u16, u8>::new()
+        .with_access_stats(true)
+        .register(
+            0x00..=0xff,
+            Box::new(Memory::<ReadWrite, u16, u8>::new(0x00, 0xff)),
+        )
+        .unwrap()
+        .register(
+            0x100..=0x1ff,
+            Box::new(Memory::<ReadWrite, u16, u8>::new(0x100, 0x1ff)),
+        )
+        .unwrap();
+
+    am.write(0x0010, 0xff).unwrap();
+    am.read(0x0010);
+    am.read(0x0011);
+    am.write(0x0110, 0xff).unwrap();
+
+    assert_eq!(
+        vec![
+            (
+                0x00..=0xff,
+                AccessStats {
+                    reads: 2,
+                    writes: 1
+                }
+            ),
+            (
+                0x100..=0x1ff,
+                AccessStats {
+                    reads: 0,
+                    writes: 1
+                }
+            )
+        ],
+        am.access_stats()
+    );
+}
+
+#[test]
+fn should_not_count_rejected_writes_in_access_stats() {
+    use crate::address_map::{AccessStats, AddressMap};
+
+    let mut am = AddressMap::<u16, u8>::new()
+        .with_access_stats(true)
+        .register(
+            0x00..=0xff,
+            Box::new(Memory::<ReadOnly, u16, u8>::new(0x00, 0xff)),
+        )
+        .unwrap();
+
+    assert!(am.write(0x0010, 0xff).is_err());
+    am.read(0x0010);
+
+    assert_eq!(
+        vec![(
+            0x00..=0xff,
+            AccessStats {
+                reads: 1,
+                writes: 0
+            }
+        )],
+        am.access_stats()
+    );
+}
+
+#[test]
+fn should_not_track_access_stats_by_default() {
+    let mut am = u16_address_map!().unwrap();
+
+    am.write(0x0010, 0xff).unwrap();
+    am.read(0x0010);
+
+    assert!(am.access_stats().is_empty());
+}
+
+#[test]
+fn should_enumerate_address_spaces_in_ascending_order_regardless_of_registration_order() {
+    use crate::address_map::{memory::ReadWrite, AddressMap};
+
+    let ranges = [0x200..=0x2ff, 0x00..=0xff, 0x300..=0x3ff, 0x100..=0x1ff];
+    let orderings = [[0, 1, 2, 3], [3, 2, 1, 0], [2, 0, 3, 1]];
+
+    for ordering in orderings.iter() {
+        let am = ordering
+            .iter()
+            .fold(AddressMap::<u16, u8>::new(), |am, &idx| {
+                let range = ranges[idx].clone();
+                let mem = Memory::<ReadWrite, u16, u8>::new(*range.start(), *range.end());
+                am.register(range, Box::new(mem)).unwrap()
+            });
+
+        assert_eq!(
+            "AddressMap [0..=255, 256..=511, 512..=767, 768..=1023]",
+            format!("{:?}", am)
+        );
+    }
+}
+
+#[test]
+fn should_read_configured_default_value_from_unmapped_addresses() {
+    use crate::address_map::AddressMap;
+
+    let am = AddressMap::<u16, u8>::new()
+        .with_default_read_value(0xff)
+        .register(
+            0x8000..=0xffff,
+            Box::new(Memory::<ReadOnly, u16, u8>::new(0x8000, 0xffff)),
+        )
+        .unwrap();
+
+    assert_eq!(0xff, am.read(0x0000));
+    assert_eq!(0x00, am.read(0x8000));
+    assert_eq!(0x00, AddressMap::<u16, u8>::new().read(0x0000));
+}
+
+#[test]
+fn should_invoke_write_watch_only_for_writes_in_range() {
+    use std::{cell::RefCell, rc::Rc};
+
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let mut am = u16_address_map!().unwrap();
+    let watched = seen.clone();
+    am.watch(0x8000..=0x80ff, move |addr, value| {
+        watched.borrow_mut().push((addr, value))
+    });
+
+    am.write(0x8001, 0x55).unwrap();
+    am.write(0x9000, 0xaa).unwrap();
+    am.read(0x8001);
+
+    assert_eq!(vec![(0x8001, 0x55)], *seen.borrow());
+}
+
+#[test]
+fn should_not_invoke_write_watch_when_the_write_fails() {
+    use crate::address_map::AddressMap;
+    use std::{cell::Cell, rc::Rc};
+
+    let calls = Rc::new(Cell::new(0));
+    let mut am = AddressMap::<u16, u8>::new()
+        .register(
+            0x8000..=0xffff,
+            Box::new(Memory::<ReadOnly, u16, u8>::new(0x8000, 0xffff)),
+        )
+        .unwrap();
+    let watched = calls.clone();
+    am.watch(0x0000..=0xffff, move |_, _| watched.set(watched.get() + 1));
+
+    assert!(am.write(0x8000, 0xff).is_err());
+    assert!(am.write(0x0000, 0xff).is_err());
+    assert_eq!(0, calls.get());
+}
+
+#[test]
+fn should_iterate_registered_regions_in_ascending_order() {
+    use crate::address_map::{memory::ReadWrite, AddressMap};
+
+    let am = AddressMap::<u16, u8>::new()
+        .register(
+            0x8000..=0xffff,
+            Box::new(Memory::<ReadOnly, u16, u8>::new(0x8000, 0xffff)),
+        )
+        .unwrap()
+        .register(
+            0x0000..=0x00ff,
+            Box::new(Memory::<ReadWrite, u16, u8>::new(0x0000, 0x00ff)),
+        )
+        .unwrap()
+        .register(
+            0x0200..=0x02ff,
+            Box::new(Memory::<ReadWrite, u16, u8>::new(0x0200, 0x02ff)),
+        )
+        .unwrap();
+
+    assert_eq!(
+        vec![0x0000..=0x00ff, 0x0200..=0x02ff, 0x8000..=0xffff],
+        am.regions().cloned().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn should_carve_an_overriding_address_space_out_of_an_enclosing_one() {
+    use crate::address_map::memory::ReadWrite;
+
+    let mut am = u16_address_map!()
+        .unwrap()
+        .register_override(
+            0x8000..=0x800f,
+            Box::new(Memory::<ReadOnly, u16, u8>::new(0x8000, 0x800f).load(vec![0xaa; 0x10])),
+        )
+        .unwrap();
+
+    assert_eq!(
+        "AddressMap [0..=32767, 32768..=32783, 32784..=65535]",
+        format!("{:?}", am)
+    );
+
+    // reads and writes inside the carved range hit the new handler.
+    assert_eq!(0xaa, am.read(0x8000));
+    assert!(am.write(0x800f, 0xff).is_err());
+
+    // the surrounding space remains mapped to the original handler.
+    am.write(0x7fff, 0x11).unwrap();
+    am.write(0x8010, 0x22).unwrap();
+    assert_eq!(0x11, am.read(0x7fff));
+    assert_eq!(0x22, am.read(0x8010));
+
+    // a range that isn't enclosed is still rejected if it overlaps.
+    let am = crate::address_map::AddressMap::<u16, u8>::new()
+        .register(
+            0x00..=0xff,
+            Box::new(Memory::<ReadWrite, u16, u8>::new(0x00, 0xff)),
+        )
+        .unwrap();
+    assert!(am
+        .register_override(
+            0x80..=0x17f,
+            Box::new(Memory::<ReadWrite, u16, u8>::new(0x80, 0x17f))
+        )
+        .is_err());
+}
+
+#[test]
+fn should_share_the_original_addressable_between_both_sides_of_an_override() {
+    /// A device with a single latch register mirrored across its range.
+    #[derive(Clone)]
+    struct Latch(u8);
+
+    impl Addressable<u16, u8> for Latch {
+        fn read(&self, _: u16) -> u8 {
+            self.0
+        }
+
+        fn write(&mut self, _: u16, value: u8) -> Result<u8, String> {
+            self.0 = value;
+            Ok(value)
+        }
+    }
+
+    let mut am = u16_address_map!(0x00..=0xff, Latch(0x00))
+        .unwrap()
+        .register_override(
+            0x80..=0x8f,
+            Box::new(Memory::<ReadOnly, u16, u8>::new(0x80, 0x8f)),
+        )
+        .unwrap();
+
+    // a write below the override is visible above it.
+    am.write(0x10, 0x42).unwrap();
+    assert_eq!(0x42, am.read(0xf0));
+
+    am.write(0xf0, 0x24).unwrap();
+    assert_eq!(0x24, am.read(0x10));
+
+    // a clone shares the addressable between its own sides, but not with the
+    // original.
+    let mut cloned = am.clone();
+    cloned.write(0x10, 0x99).unwrap();
+    assert_eq!(0x99, cloned.read(0xf0));
+    assert_eq!(0x24, am.read(0x10));
+    assert_eq!(0x24, am.read(0xf0));
+}
+
+#[test]
+fn should_fall_back_to_the_default_value_after_deregistering_a_space() {
+    use crate::address_map::memory::ReadWrite;
+
+    let mut am =
+        u16_address_map!(0x00..=0xff, Memory::<ReadWrite, u16, u8>::new(0x00, 0xff)).unwrap();
+    am.write(0x10, 0xff).unwrap();
+
+    assert!(am.deregister(&(0x00..=0xff)).is_some());
+    assert_eq!(0x00, am.read(0x10));
+    assert!(am.write(0x10, 0xff).is_err());
+}
+
+#[test]
+fn should_not_deregister_a_range_that_does_not_exactly_match() {
+    let mut am =
+        u16_address_map!(0x00..=0xff, Memory::<ReadOnly, u16, u8>::new(0x00, 0xff)).unwrap();
+
+    assert!(am.deregister(&(0x00..=0x7f)).is_none());
+    assert!(am.deregister(&(0x10..=0xff)).is_none());
+    assert!(am.deregister(&(0x00..=0xff)).is_some());
+}
+
+#[test]
+fn should_read_and_write_little_endian_words() {
+    let mut am = u16_address_map!().unwrap();
+
+    assert!(am.write_u16(0x1000, 0x1234).is_ok());
+    assert_eq!((0x34, 0x12), (am.read(0x1000), am.read(0x1001)));
+    assert_eq!(0x1234, am.read_u16(0x1000));
+}
+
+#[test]
+fn should_wrap_word_access_at_the_end_of_the_address_space() {
+    let mut am = u16_address_map!().unwrap();
+
+    assert!(am.write_u16(0xffff, 0xabcd).is_ok());
+    assert_eq!((0xcd, 0xab), (am.read(0xffff), am.read(0x0000)));
+    assert_eq!(0xabcd, am.read_u16(0xffff));
+}
+
+#[test]
+fn should_load_a_contiguous_slice_into_the_address_map() {
+    let mut am = u16_address_map!().unwrap();
+
+    assert!(am.load(0x0200, &[0xa9, 0x55, 0x8d]).is_ok());
+    assert_eq!(
+        vec![0xa9, 0x55, 0x8d],
+        (0x0200..0x0203)
+            .map(|addr| am.read(addr))
+            .collect::<Vec<u8>>()
+    );
+}
+
+#[test]
+fn should_return_the_first_write_error_when_loading_a_slice() {
+    use crate::address_map::memory::ReadWrite;
+
+    // RAM followed by read-only memory.
+    let mut am = u16_address_map!(0x00..=0x01, Memory::<ReadWrite, u16, u8>::new(0x00, 0x01))
+        .unwrap()
+        .register(
+            0x02..=0x03,
+            Box::new(Memory::<ReadOnly, u16, u8>::new(0x02, 0x03)),
+        )
+        .unwrap();
+
+    assert!(am.load(0x00, &[0x01, 0x02, 0x03, 0x04]).is_err());
+    // writes preceding the failure are retained.
+    assert_eq!((0x01, 0x02), (am.read(0x00), am.read(0x01)));
+}
@@ -1,16 +1,19 @@
 use crate::address_map::{AddressMap, Addressable};
-use crate::cpu::Execute;
 use crate::cpu::{register::Register, Cpu, StepState};
 use parcel::Parser;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 use super::ExecuteMut;
 
 mod memory;
 mod microcode;
 mod operations;
-mod register;
+pub mod register;
 mod u12;
 
+pub use operations::{chip8_implemented_opcodes, OpcodePattern};
+
 /// Defines a trait for implementing random number generation.
 pub trait GenerateRandom<T> {
     /// Random should return a value of a constrained type T on each invocation.
@@ -92,6 +95,17 @@ impl Display {
         64
     }
 
+    /// Returns a copy of every pixel in the display in row-major order.
+    pub fn frame(&self) -> [bool; 2048] {
+        let mut frame = [false; 2048];
+        self.inner
+            .iter()
+            .flatten()
+            .zip(frame.iter_mut())
+            .for_each(|(&pixel, dest)| *dest = pixel);
+        frame
+    }
+
     /// Returns the maximum number of rows.
     pub fn y_max() -> usize {
         32
@@ -134,9 +148,116 @@ impl Display {
     }
 }
 
+/// Quirks captures the behaviors that differ between CHIP-8 interpreters,
+/// allowing a ROM to be run against the variant it was written for. The
+/// default profile matches CHIP-48 load and store behavior while keeping the
+/// original V0 indexed jump.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Quirks {
+    /// When set, Fx55 and Fx65 leave I pointing past the last register
+    /// accessed (I = I + X + 1). Otherwise I is left unchanged.
+    pub load_store_increments_i: bool,
+
+    /// When set, Bnnn is treated as the CHIP-48 BXNN jump to XNN + VX.
+    /// Otherwise it jumps to NNN + V0.
+    pub jump_with_offset_uses_vx: bool,
+
+    /// When set, 8xy1, 8xy2 and 8xy3 reset VF to 0 after storing their
+    /// result. Otherwise VF is left untouched.
+    pub logic_resets_vf: bool,
+
+    /// When set, 8xy6 and 8xyE shift the value of Vy into Vx. Otherwise Vx
+    /// is shifted in place and Vy is ignored.
+    pub shift_uses_vy: bool,
+
+    /// When set, Fx1E sets VF to 1 if I overflows past 0x0FFF and to 0
+    /// otherwise, as the Amiga interpreter did. Otherwise VF is left
+    /// untouched.
+    pub add_i_sets_vf_on_overflow: bool,
+}
+
+impl Quirks {
+    /// Returns the quirks of the original COSMAC VIP interpreter.
+    pub fn cosmac_vip() -> Self {
+        Self {
+            load_store_increments_i: true,
+            jump_with_offset_uses_vx: false,
+            logic_resets_vf: true,
+            shift_uses_vy: true,
+            add_i_sets_vf_on_overflow: false,
+        }
+    }
+
+    /// Returns the quirks of the CHIP-48 interpreter.
+    pub fn chip48() -> Self {
+        Self {
+            load_store_increments_i: false,
+            jump_with_offset_uses_vx: true,
+            logic_resets_vf: false,
+            shift_uses_vy: false,
+            add_i_sets_vf_on_overflow: false,
+        }
+    }
+}
+
 /// Represents the address the program counter is set to on chip reset.
 const RESET_PC_VECTOR: u16 = 0x200;
 
+/// The number of slots in the call stack.
+const STACK_DEPTH: usize = 16;
+
+/// Represents the address the built-in hex font is loaded at.
+const FONT_START_ADDR: u16 = 0x050;
+
+/// The height, in bytes, of each sprite in the built-in hex font.
+const FONT_SPRITE_HEIGHT: u16 = 5;
+
+/// The built-in 4x5 sprites for the hex digits 0 through F.
+const FONT: [u8; 0x50] = [
+    0xf0, 0x90, 0x90, 0x90, 0xf0, // 0
+    0x20, 0x60, 0x20, 0x20, 0x70, // 1
+    0xf0, 0x10, 0xf0, 0x80, 0xf0, // 2
+    0xf0, 0x10, 0xf0, 0x10, 0xf0, // 3
+    0x90, 0x90, 0xf0, 0x10, 0x10, // 4
+    0xf0, 0x80, 0xf0, 0x10, 0xf0, // 5
+    0xf0, 0x80, 0xf0, 0x90, 0xf0, // 6
+    0xf0, 0x10, 0x20, 0x40, 0x40, // 7
+    0xf0, 0x90, 0xf0, 0x90, 0xf0, // 8
+    0xf0, 0x90, 0xf0, 0x10, 0xf0, // 9
+    0xf0, 0x90, 0xf0, 0x90, 0x90, // A
+    0xe0, 0x90, 0xe0, 0x90, 0xe0, // B
+    0xf0, 0x80, 0x80, 0x80, 0xf0, // C
+    0xe0, 0x90, 0x90, 0x90, 0xe0, // D
+    0xf0, 0x80, 0xf0, 0x80, 0xf0, // E
+    0xf0, 0x80, 0xf0, 0x80, 0x80, // F
+];
+
+/// Returns the address of the font sprite for the hex digit in the low nibble
+/// of `digit`.
+fn font_sprite_addr(digit: u8) -> u16 {
+    FONT_START_ADDR + u16::from(digit & 0x0f) * FONT_SPRITE_HEIGHT
+}
+
+/// The frequency, in Hz, of the tone generated while the sound timer is active.
+const TONE_FREQUENCY: f32 = 440.0;
+
+/// The amplitude of the generated square wave.
+const TONE_AMPLITUDE: f32 = 0.25;
+
+/// Chip8Display provides a rendering backend for the CHIP-8 display,
+/// allowing the emulator core to remain headless. `draw` is invoked with the
+/// full frame, in row-major order, after each instruction that modifies the
+/// display.
+pub trait Chip8Display {
+    fn draw(&mut self, framebuffer: &[bool; 2048]);
+}
+
+impl std::fmt::Debug for dyn Chip8Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Chip8Display")
+    }
+}
+
 /// Chip8 represents a CHIP-8 CPU.
 #[derive(Debug, Clone)]
 pub struct Chip8<R> {
@@ -146,10 +267,18 @@ pub struct Chip8<R> {
     st: register::ClockDecrementing,
     pc: register::ProgramCounter,
     sp: register::StackPointer,
+    /// The number of return addresses held on the call stack.
+    stack_depth: usize,
+    /// The error that halted the last call to `run`, if any.
+    fault: Option<String>,
     i: register::GeneralPurpose<u16>,
-    gp_registers: [register::GeneralPurpose<u8>; 0xf],
+    gp_registers: [register::GeneralPurpose<u8>; 0x10],
     display: Display,
+    renderer: Option<Rc<RefCell<dyn Chip8Display>>>,
     interrupt: Option<Interrupt>,
+    keypad: [bool; 0x10],
+    quirks: Quirks,
+    audio_phase: f32,
     rng: R,
 }
 
@@ -190,6 +319,41 @@ impl<R> Chip8<R> {
         self
     }
 
+    /// Seeds all sixteen general purpose registers, V0 through VF, from an
+    /// array indexed by register number.
+    pub fn with_v_registers(mut self, values: [u8; 0x10]) -> Self {
+        self.set_v_registers(values);
+        self
+    }
+
+    /// Seeds all sixteen general purpose registers, V0 through VF, in place
+    /// from an array indexed by register number.
+    pub fn set_v_registers(&mut self, values: [u8; 0x10]) {
+        for (reg, value) in self.gp_registers.iter_mut().zip(values.iter()) {
+            *reg = register::GeneralPurpose::with_value(*value);
+        }
+    }
+
+    /// Returns an instance of Chip8 with the key held down or released on
+    /// the keypad.
+    pub fn with_key(mut self, key: KeyInputValue, pressed: bool) -> Self {
+        self.set_key(key, pressed);
+        self
+    }
+
+    /// Holds down or releases a key on the keypad in place.
+    pub fn set_key(&mut self, key: KeyInputValue, pressed: bool) {
+        self.keypad[key as usize] = pressed;
+    }
+
+    /// Returns true if the key with the given index, masked to the low
+    /// nibble, is held down on the keypad or latched by a keypress interrupt.
+    pub fn is_key_pressed(&self, key: u8) -> bool {
+        let key = key & 0x0f;
+        let latched = matches!(self.interrupt, Some(Interrupt::KeyPress(iv)) if iv as u8 == key);
+        self.keypad[key as usize] || latched
+    }
+
     /// Provides a convenient method for unwrapping a GpRegister enum to a
     /// corresponding read of it's namesake register.
     pub fn read_gp_register(&self, reg: register::GpRegisters) -> u8 {
@@ -200,6 +364,97 @@ impl<R> Chip8<R> {
             .unwrap()
     }
 
+    /// Returns the display packed at 1 bit per pixel in row-major order, with
+    /// the leftmost pixel of each byte stored in the most significant bit.
+    pub fn framebuffer_packed(&self) -> Vec<u8> {
+        self.display
+            .inner
+            .iter()
+            .flat_map(|row| row.chunks(8))
+            .map(|pixels| {
+                pixels
+                    .iter()
+                    .fold(0u8, |byte, &pixel_on| (byte << 1) | pixel_on as u8)
+            })
+            .collect()
+    }
+
+    /// Returns the display expanded to 4 bytes per pixel in row-major order,
+    /// using the `on` and `off` colors for set and unset pixels respectively.
+    pub fn framebuffer_rgba(&self, on: [u8; 4], off: [u8; 4]) -> Vec<u8> {
+        self.display
+            .inner
+            .iter()
+            .flatten()
+            .flat_map(|&pixel_on| if pixel_on { on } else { off })
+            .collect()
+    }
+
+    /// Decrements the delay and sound timers by one, saturating at zero.
+    /// Callers should invoke this at 60Hz, independent of the rate that
+    /// instructions are stepped.
+    pub fn tick_timers(&mut self) {
+        self.dt = self.dt.tick();
+        self.st = self.st.tick();
+    }
+
+    /// Returns the error that halted the last call to `run`, such as an
+    /// undecodable opcode or a call stack overflow, if it stopped early.
+    pub fn fault(&self) -> Option<&str> {
+        self.fault.as_deref()
+    }
+
+    /// Returns true while the sound timer is non-zero, signaling that a tone
+    /// should be played.
+    pub fn sound_active(&self) -> bool {
+        self.st.read() != 0
+    }
+
+    /// Fills the buffer with mono samples of a square wave tone while the
+    /// sound timer is active, or silence otherwise. The phase of the tone is
+    /// carried between calls so that consecutive buffers are continuous.
+    pub fn fill_audio(&mut self, out: &mut [f32], sample_rate: u32) {
+        if self.st.read() == 0 {
+            out.iter_mut().for_each(|sample| *sample = 0.0);
+            return;
+        }
+
+        let step = TONE_FREQUENCY / sample_rate as f32;
+        for sample in out.iter_mut() {
+            *sample = if self.audio_phase < 0.5 {
+                TONE_AMPLITUDE
+            } else {
+                -TONE_AMPLITUDE
+            };
+            self.audio_phase = (self.audio_phase + step).fract();
+        }
+    }
+
+    /// Returns the registers, stack, timers and display to their initial state
+    /// and the program counter to the reset vector, leaving the contents of
+    /// memory, and as such any loaded program, intact.
+    pub fn soft_reset(&mut self) {
+        self.stack = memory::Ring::new(STACK_DEPTH);
+        self.dt = register::ClockDecrementing::default();
+        self.st = register::ClockDecrementing::default();
+        self.pc = register::ProgramCounter::with_value(RESET_PC_VECTOR);
+        self.sp = register::StackPointer::default();
+        self.stack_depth = 0;
+        self.fault = None;
+        self.i = register::GeneralPurpose::default();
+        self.gp_registers = [register::GeneralPurpose::default(); 0x10];
+        self.display = Display::default();
+        self.interrupt = None;
+        self.keypad = [false; 0x10];
+        self.audio_phase = 0.0;
+    }
+
+    /// Returns an instance of Chip8 using the provided interpreter quirks.
+    pub fn with_quirks(mut self, quirks: Quirks) -> Self {
+        self.quirks = quirks;
+        self
+    }
+
     /// Returns an instance of Chip8 with a new random number generator.
     pub fn with_rng<NR>(self, rng: NR) -> Chip8<NR> {
         Chip8 {
@@ -209,10 +464,16 @@ impl<R> Chip8<R> {
             st: self.st,
             pc: self.pc,
             sp: self.sp,
+            stack_depth: self.stack_depth,
+            fault: self.fault,
             i: self.i,
             gp_registers: self.gp_registers,
             display: self.display,
+            renderer: self.renderer,
             interrupt: self.interrupt,
+            keypad: self.keypad,
+            quirks: self.quirks,
+            audio_phase: self.audio_phase,
             rng,
         }
     }
@@ -230,14 +491,39 @@ impl<R> Chip8<R> {
             st: self.st,
             pc: self.pc,
             sp: self.sp,
+            stack_depth: self.stack_depth,
+            fault: self.fault,
             i: self.i,
             gp_registers: self.gp_registers,
             display: self.display,
+            renderer: self.renderer,
             interrupt: (f)(),
+            keypad: self.keypad,
+            quirks: self.quirks,
+            audio_phase: self.audio_phase,
             rng: self.rng,
         }
     }
 
+    /// Returns an instance of Chip8 that draws each modified frame to the
+    /// provided display. The display is shared with the caller, and with any
+    /// clones of the cpu, rather than being cloned itself.
+    pub fn with_renderer<D>(mut self, renderer: Rc<RefCell<D>>) -> Self
+    where
+        D: Chip8Display + 'static,
+    {
+        self.renderer = Some(renderer);
+        self
+    }
+
+    /// Draws the current frame to the renderer, if one is configured.
+    fn render(&mut self) {
+        let frame = self.display.frame();
+        if let Some(renderer) = &self.renderer {
+            renderer.borrow_mut().draw(&frame);
+        }
+    }
+
     /// Takes and invokes a function that modies the types display, returning
     ///the newly modified state.
     pub fn with_display<F>(self, f: F) -> Self
@@ -251,10 +537,16 @@ impl<R> Chip8<R> {
             st: self.st,
             pc: self.pc,
             sp: self.sp,
+            stack_depth: self.stack_depth,
+            fault: self.fault,
             i: self.i,
             gp_registers: self.gp_registers,
             display: (f)(self.display),
+            renderer: self.renderer,
             interrupt: None,
+            keypad: self.keypad,
+            quirks: self.quirks,
+            audio_phase: self.audio_phase,
             rng: self.rng,
         }
     }
@@ -280,10 +572,17 @@ where
         type Ram =
             crate::address_map::memory::Memory<crate::address_map::memory::ReadWrite, u16, u8>;
 
+        let mut interpreter_rom = vec![0u8; 0x200];
+        let font_start = FONT_START_ADDR as usize;
+        interpreter_rom[font_start..font_start + FONT.len()].copy_from_slice(&FONT);
+
         Self {
-            stack: memory::Ring::new(16),
+            stack: memory::Ring::new(STACK_DEPTH),
             address_space: AddressMap::default()
-                .register(0..=0x1ff, Box::new(Rom::new(0, 0x1ff)))
+                .register(
+                    0..=0x1ff,
+                    Box::new(Rom::new(0, 0x1ff).load(interpreter_rom)),
+                )
                 .unwrap()
                 .register(0x200..=0xfff, Box::new(Ram::new(0x200, 0xfff)))
                 .unwrap(),
@@ -291,10 +590,16 @@ where
             st: register::ClockDecrementing::default(),
             pc: register::ProgramCounter::with_value(RESET_PC_VECTOR),
             sp: register::StackPointer::default(),
+            stack_depth: 0,
+            fault: None,
             i: register::GeneralPurpose::default(),
-            gp_registers: [register::GeneralPurpose::default(); 0xf],
+            gp_registers: [register::GeneralPurpose::default(); 0x10],
             display: Display::default(),
+            renderer: None,
             interrupt: None,
+            keypad: [false; 0x10],
+            quirks: Quirks::default(),
+            audio_phase: 0.0,
             rng: <R>::default(),
         }
     }
@@ -304,15 +609,103 @@ impl<R> Cpu<Chip8<R>> for Chip8<R>
 where
     R: 'static + Clone,
 {
-    fn run(self, cycles: usize) -> StepState<Chip8<R>> {
-        let state = self
-            .clone()
+    /// Steps the cpu for the provided number of instructions, halting early
+    /// and recording the error, retrievable with `fault`, if an instruction
+    /// fails to execute.
+    fn run(mut self, cycles: usize) -> StepState<Chip8<R>> {
+        self.fault = None;
+        for _ in 0..cycles {
+            if let Err(e) = self.step() {
+                self.fault = Some(e);
+                break;
+            }
+        }
+
+        StepState::from(self)
+    }
+}
+
+impl<R> Chip8<R>
+where
+    R: 'static,
+{
+    /// Decodes the instruction at the program counter, returning its opcode
+    /// along with the microcode that executes it, including the increment of
+    /// the program counter past it. An error is returned if the instruction
+    /// would push onto a full call stack.
+    fn decode(&self) -> Result<(u16, Vec<microcode::Microcode>), String> {
+        let pc = self.pc.read();
+        let opcodes: [(usize, u8); 2] = [
+            (pc as usize, self.address_space.read(pc)),
+            ((pc as usize + 1), self.address_space.read(pc + 1)),
+        ];
+        let opcode = u16::from_be_bytes([opcodes[0].1, opcodes[1].1]);
+
+        // Parse correct operation
+        let ops: Box<dyn crate::cpu::Generate<Chip8<_>, _>> =
+            match operations::OpcodeVariantParser.parse(&opcodes[..]) {
+                Ok(parcel::MatchStatus::Match {
+                    span: _,
+                    remainder: _,
+                    inner: op,
+                }) => Ok(op),
+                _ => Err(format!("No match found for {:#02x}", opcode)),
+            }?;
+
+        let microcode_steps = ops
+            .generate(self)
             .into_iter()
-            .take(cycles)
-            .flatten()
-            .fold(self, |c, mc| mc.execute(c));
-        StepState::from(state)
+            .chain(vec![microcode::Microcode::Inc16bitRegister(
+                // increment the PC by instruction size.
+                microcode::Inc16bitRegister::new(register::WordRegisters::ProgramCounter, 2),
+            )])
+            .collect::<Vec<_>>();
+
+        let pushes_stack = microcode_steps
+            .iter()
+            .any(|mc| matches!(mc, microcode::Microcode::PushStack(_)));
+        if pushes_stack && self.stack_depth == STACK_DEPTH {
+            return Err(format!(
+                "call stack overflow at {:#06x}: exceeded {} nested calls",
+                pc, STACK_DEPTH
+            ));
+        }
+
+        Ok((opcode, microcode_steps))
+    }
+
+    /// Fetches, decodes and executes the single instruction at the program
+    /// counter, returning its opcode. An error is returned, leaving the cpu
+    /// unmodified, if the opcode can't be decoded or a call would overflow
+    /// the call stack.
+    pub fn step(&mut self) -> Result<u16, String> {
+        let (opcode, microcode_steps) = self.decode()?;
+        for mc in microcode_steps.iter() {
+            self.execute_mut(mc)
+        }
+
+        if modifies_display(&microcode_steps) {
+            self.render();
+        }
+
+        Ok(opcode)
     }
+
+    /// Loads a program into memory starting at the reset vector, returning an
+    /// error if it doesn't fit in the writable address space.
+    pub fn load_program(&mut self, program: &[u8]) -> Result<(), String> {
+        self.address_space.load(RESET_PC_VECTOR, program)
+    }
+}
+
+/// Returns true if any of the microcode steps write to the display.
+fn modifies_display(microcode_steps: &[microcode::Microcode]) -> bool {
+    microcode_steps.iter().any(|mc| {
+        matches!(
+            mc,
+            microcode::Microcode::SetDisplayPixel(_) | microcode::Microcode::SetDisplayRange(_)
+        )
+    })
 }
 
 impl<R> IntoIterator for Chip8<R>
@@ -350,39 +743,22 @@ where
     type Item = Vec<microcode::Microcode>;
 
     fn next(&mut self) -> Option<Vec<microcode::Microcode>> {
-        let pc = self.state.pc.read();
-        let opcodes: [(usize, u8); 2] = [
-            (pc as usize, self.state.address_space.read(pc)),
-            ((pc as usize + 1), self.state.address_space.read(pc + 1)),
-        ];
-
-        // Parse correct operation
-        let ops: Box<dyn crate::cpu::Generate<Chip8<_>, _>> =
-            match operations::OpcodeVariantParser.parse(&opcodes[..]) {
-                Ok(parcel::MatchStatus::Match {
-                    span: _,
-                    remainder: _,
-                    inner: op,
-                }) => Ok(op),
-                _ => Err(format!(
-                    "No match found for {:#02x}",
-                    u16::from_be_bytes([opcodes[0].1, opcodes[1].1])
-                )),
+        // Halt on an instruction that fails to decode
+        let (_, microcode_steps) = match self.state.decode() {
+            Ok(decoded) => decoded,
+            Err(e) => {
+                self.state.fault = Some(e);
+                return None;
             }
-            .unwrap();
+        };
 
-        let microcode_steps: Vec<microcode::Microcode> = ops
-            .generate(&self.state)
-            .into_iter()
-            .chain(vec![microcode::Microcode::Inc16bitRegister(
-                // increment the PC by instruction size.
-                microcode::Inc16bitRegister::new(register::WordRegisters::ProgramCounter, 2),
-            )])
-            .collect();
+        for mc in microcode_steps.iter() {
+            self.state.execute_mut(mc)
+        }
 
-        self.state = microcode_steps
-            .iter()
-            .fold(self.state.clone(), |cpu, mc| mc.execute(cpu));
+        if modifies_display(&microcode_steps) {
+            self.state.render();
+        }
 
         Some(microcode_steps)
     }
@@ -502,14 +878,28 @@ impl<R> crate::cpu::ExecuteMut<microcode::Inc16bitRegister> for Chip8<R> {
 }
 
 impl<R> crate::cpu::ExecuteMut<microcode::Dec16bitRegister> for Chip8<R> {
-    fn execute_mut(&mut self, _: &microcode::Dec16bitRegister) {}
+    fn execute_mut(&mut self, mc: &microcode::Dec16bitRegister) {
+        match mc.register {
+            register::WordRegisters::I => {
+                let i = self.i.read();
+                self.i = register::GeneralPurpose::with_value(i.wrapping_sub(mc.value));
+            }
+            register::WordRegisters::ProgramCounter => {
+                let pc = self.pc.read();
+                self.pc = register::ProgramCounter::with_value(pc.wrapping_sub(mc.value));
+            }
+        }
+    }
 }
 
 impl<R> crate::cpu::ExecuteMut<microcode::PushStack> for Chip8<R> {
     fn execute_mut(&mut self, mc: &microcode::PushStack) {
-        // increment stack pointer before doing any data writes.
+        // increment stack pointer before doing any data writes. The stack is a
+        // ring, so the 16th nested call wraps around to the first slot, with
+        // overflow past that guarded by `decode`.
         let sp = self.sp.read().wrapping_add(1);
-        self.sp.write(sp);
+        self.sp = self.sp.write(sp);
+        self.stack_depth += 1;
 
         // push the value from mc onto the new stack location.
         // due to the protections applied by the `StackPointer`'s `.read()
@@ -523,7 +913,8 @@ impl<R> crate::cpu::ExecuteMut<microcode::PopStack> for Chip8<R> {
     fn execute_mut(&mut self, _: &microcode::PopStack) {
         // decrement stack pointer.
         let sp = self.sp.read().wrapping_sub(1);
-        self.sp.write(sp);
+        self.sp = self.sp.write(sp);
+        self.stack_depth = self.stack_depth.saturating_sub(1);
     }
 }
 
@@ -596,6 +987,644 @@ mod tests {
         assert_eq!(0xff, state.read_gp_register(register::GpRegisters::V0))
     }
 
+    #[test]
+    fn should_execute_load_immediate() {
+        let mut cpu = Chip8::<()>::default().with_rng(|| 0u8);
+        cpu.address_space.write(0x200, 0x65).unwrap();
+        cpu.address_space.write(0x201, 0x42).unwrap();
+
+        let state = cpu.run(1).unwrap();
+        assert_eq!(0x42, state.read_gp_register(register::GpRegisters::V5));
+        assert_eq!(0x202, state.pc.read());
+    }
+
+    #[test]
+    fn should_copy_register_without_linking_source_and_destination() {
+        let mut cpu = Chip8::<()>::default().with_rng(|| 0u8).with_gp_register(
+            register::GpRegisters::V1,
+            register::GeneralPurpose::with_value(0x0f),
+        );
+        // LD V0, V1 followed by LD V1, 0x33
+        for (offset, byte) in [0x80, 0x10, 0x61, 0x33].iter().enumerate() {
+            cpu.address_space
+                .write(0x200 + offset as u16, *byte)
+                .unwrap();
+        }
+
+        let state = cpu.run(2).unwrap();
+        assert_eq!(0x0f, state.read_gp_register(register::GpRegisters::V0));
+        assert_eq!(0x33, state.read_gp_register(register::GpRegisters::V1));
+    }
+
+    #[test]
+    fn should_set_vf_on_register_add_only_when_the_sum_carries() {
+        for (vx, vy, sum, carry) in [(0xff, 0x01, 0x00, 1), (0x10, 0x20, 0x30, 0)].iter() {
+            let mut cpu = Chip8::<()>::default()
+                .with_rng(|| 0u8)
+                .with_gp_register(
+                    register::GpRegisters::V0,
+                    register::GeneralPurpose::with_value(*vx),
+                )
+                .with_gp_register(
+                    register::GpRegisters::V1,
+                    register::GeneralPurpose::with_value(*vy),
+                );
+            cpu.address_space.write(0x200, 0x80).unwrap();
+            cpu.address_space.write(0x201, 0x14).unwrap();
+
+            let state = cpu.run(1).unwrap();
+            assert_eq!(*sum, state.read_gp_register(register::GpRegisters::V0));
+            assert_eq!(*carry, state.read_gp_register(register::GpRegisters::Vf));
+        }
+    }
+
+    #[test]
+    fn should_load_a_12_bit_address_into_i() {
+        let mut cpu = Chip8::<()>::default()
+            .with_rng(|| 0u8)
+            .with_i_register(register::GeneralPurpose::with_value(0xffff));
+        cpu.address_space.write(0x200, 0xa2).unwrap();
+        cpu.address_space.write(0x201, 0xf0).unwrap();
+
+        let state = cpu.run(1).unwrap();
+        assert_eq!(0x2f0, state.i.read());
+    }
+
+    /// Loads a single row sprite at 0x300, and a DRW V0, V1, 1 instruction
+    /// at the reset vector, returning a cpu with V0 and V1 set to x and y.
+    fn cpu_with_single_row_sprite(sprite: u8, x: u8, y: u8) -> Chip8<()> {
+        let mut cpu = Chip8::<()>::default()
+            .with_i_register(register::GeneralPurpose::with_value(0x300))
+            .with_gp_register(
+                register::GpRegisters::V0,
+                register::GeneralPurpose::with_value(x),
+            )
+            .with_gp_register(
+                register::GpRegisters::V1,
+                register::GeneralPurpose::with_value(y),
+            );
+        cpu.address_space.write(0x300, sprite).unwrap();
+        cpu.address_space.write(0x200, 0xd0).unwrap();
+        cpu.address_space.write(0x201, 0x11).unwrap();
+        cpu
+    }
+
+    #[test]
+    fn should_draw_a_sprite_at_the_coordinates_in_vx_and_vy() {
+        let state = cpu_with_single_row_sprite(0b1010_0000, 4, 2)
+            .run(1)
+            .unwrap();
+
+        assert_eq!(Some(true), state.display.pixel(4, 2));
+        assert_eq!(Some(false), state.display.pixel(5, 2));
+        assert_eq!(Some(true), state.display.pixel(6, 2));
+        assert_eq!(0, state.read_gp_register(register::GpRegisters::Vf));
+    }
+
+    #[test]
+    fn should_wrap_sprite_pixels_at_the_display_edges() {
+        let mut cpu = cpu_with_single_row_sprite(0b1100_0000, 63, 31);
+        cpu.address_space.write(0x301, 0b1000_0000).unwrap();
+        // draw two rows.
+        cpu.address_space.write(0x201, 0x12).unwrap();
+
+        let state = cpu.run(1).unwrap();
+        assert_eq!(Some(true), state.display.pixel(63, 31));
+        assert_eq!(Some(true), state.display.pixel(0, 31));
+        assert_eq!(Some(true), state.display.pixel(63, 0));
+    }
+
+    #[test]
+    fn should_set_vf_when_drawing_erases_a_pixel() {
+        // draw the same sprite twice, erasing it the second time.
+        let mut cpu = cpu_with_single_row_sprite(0b1000_0000, 0, 0);
+        cpu.address_space.write(0x202, 0xd0).unwrap();
+        cpu.address_space.write(0x203, 0x11).unwrap();
+
+        let state = cpu.clone().run(1).unwrap();
+        assert_eq!(0, state.read_gp_register(register::GpRegisters::Vf));
+
+        let state = cpu.run(2).unwrap();
+        assert_eq!(Some(false), state.display.pixel(0, 0));
+        assert_eq!(1, state.read_gp_register(register::GpRegisters::Vf));
+    }
+
+    #[test]
+    fn should_round_trip_a_value_through_the_delay_timer() {
+        let mut cpu = Chip8::<()>::default().with_gp_register(
+            register::GpRegisters::V3,
+            register::GeneralPurpose::with_value(0x42),
+        );
+        // LD DT, V3; LD ST, V3; LD V4, DT
+        for (offset, byte) in [0xf3, 0x15, 0xf3, 0x18, 0xf4, 0x07].iter().enumerate() {
+            cpu.address_space
+                .write(0x200 + offset as u16, *byte)
+                .unwrap();
+        }
+
+        let state = cpu.run(3).unwrap();
+        assert_eq!(0x42, state.dt.read());
+        assert_eq!(0x42, state.st.read());
+        assert_eq!(0x42, state.read_gp_register(register::GpRegisters::V4));
+    }
+
+    #[test]
+    fn should_point_i_at_the_font_sprite_for_the_digit_in_vx() {
+        let mut cpu = Chip8::<()>::default().with_gp_register(
+            register::GpRegisters::V2,
+            register::GeneralPurpose::with_value(0x0a),
+        );
+        // LD F, V2
+        cpu.address_space.write(0x200, 0xf2).unwrap();
+        cpu.address_space.write(0x201, 0x29).unwrap();
+
+        let state = cpu.run(1).unwrap();
+        let i = state.i.read();
+        assert_eq!(0x050 + 0x0a * 5, i);
+        assert_eq!(
+            vec![0xf0, 0x90, 0xf0, 0x90, 0x90],
+            (i..i + 5)
+                .map(|addr| state.address_space.read(addr))
+                .collect::<Vec<u8>>()
+        );
+    }
+
+    #[test]
+    fn should_store_the_bcd_digits_of_vx_at_i() {
+        let mut cpu = Chip8::<()>::default()
+            .with_i_register(register::GeneralPurpose::with_value(0x300))
+            .with_gp_register(
+                register::GpRegisters::V4,
+                register::GeneralPurpose::with_value(0x9c),
+            );
+        // LD B, V4
+        cpu.address_space.write(0x200, 0xf4).unwrap();
+        cpu.address_space.write(0x201, 0x33).unwrap();
+
+        let state = cpu.run(1).unwrap();
+        assert_eq!(1, state.address_space.read(0x300));
+        assert_eq!(5, state.address_space.read(0x301));
+        assert_eq!(6, state.address_space.read(0x302));
+    }
+
+    #[test]
+    fn should_restore_pc_from_the_top_of_the_stack_on_ret() {
+        use crate::cpu::Execute;
+
+        let mut cpu = microcode::Microcode::PushStack(microcode::PushStack::new(0x400))
+            .execute(Chip8::<()>::default());
+        assert_eq!(1, cpu.sp.read());
+        // RET
+        cpu.address_space.write(0x200, 0x00).unwrap();
+        cpu.address_space.write(0x201, 0xee).unwrap();
+
+        let state = cpu.run(1).unwrap();
+        assert_eq!(0x400, state.pc.read());
+        assert_eq!(0, state.sp.read());
+    }
+
+    #[test]
+    fn should_return_to_the_instruction_following_a_call() {
+        let mut cpu = Chip8::<()>::default();
+        // CALL 0x300; LD V0, 0x11; ... 0x300: LD V1, 0x22; RET
+        for (offset, byte) in [0x23, 0x00, 0x60, 0x11].iter().enumerate() {
+            cpu.address_space
+                .write(0x200 + offset as u16, *byte)
+                .unwrap();
+        }
+        for (offset, byte) in [0x61, 0x22, 0x00, 0xee].iter().enumerate() {
+            cpu.address_space
+                .write(0x300 + offset as u16, *byte)
+                .unwrap();
+        }
+
+        let state = cpu.run(4).unwrap();
+        assert_eq!(0x204, state.pc.read());
+        assert_eq!(0, state.sp.read());
+        assert_eq!(0x11, state.read_gp_register(register::GpRegisters::V0));
+        assert_eq!(0x22, state.read_gp_register(register::GpRegisters::V1));
+    }
+
+    #[test]
+    fn should_unwind_sixteen_nested_calls() {
+        let mut cpu = Chip8::<()>::default();
+        // 0x200 + 2n: CALL 0x202 + 2n, nesting 16 calls deep, followed by a
+        // RET at 0x220.
+        for level in 0..STACK_DEPTH as u16 {
+            let addr = 0x200 + level * 2;
+            let [hi, lo] = (0x2000 | (addr + 2)).to_be_bytes();
+            cpu.address_space.write(addr, hi).unwrap();
+            cpu.address_space.write(addr + 1, lo).unwrap();
+        }
+        cpu.address_space.write(0x220, 0x00).unwrap();
+        cpu.address_space.write(0x221, 0xee).unwrap();
+
+        for _ in 0..STACK_DEPTH {
+            assert!(cpu.step().is_ok());
+        }
+
+        // return from each level in turn, most recent call first.
+        let returns: Vec<u16> = (0..STACK_DEPTH)
+            .map(|_| {
+                cpu.pc = register::ProgramCounter::with_value(0x220);
+                cpu.step().unwrap();
+                cpu.pc.read()
+            })
+            .collect();
+        assert_eq!(
+            (0x202..=0x220).rev().step_by(2).collect::<Vec<u16>>(),
+            returns
+        );
+    }
+
+    #[test]
+    fn should_return_an_error_when_nested_calls_overflow_the_stack() {
+        let mut cpu = Chip8::<()>::default();
+        // CALL 0x200, recursing until the stack is exhausted.
+        cpu.address_space.write(0x200, 0x22).unwrap();
+        cpu.address_space.write(0x201, 0x00).unwrap();
+
+        for _ in 0..STACK_DEPTH {
+            assert!(cpu.step().is_ok());
+        }
+        assert_eq!(
+            Err("call stack overflow at 0x0200: exceeded 16 nested calls".to_string()),
+            cpu.step()
+        );
+        assert_eq!(0x200, cpu.pc.read());
+    }
+
+    #[test]
+    fn should_halt_run_and_record_an_error_when_nested_calls_overflow_the_stack() {
+        let mut cpu = Chip8::<()>::default();
+        // CALL 0x200, recursing until the stack is exhausted.
+        cpu.address_space.write(0x200, 0x22).unwrap();
+        cpu.address_space.write(0x201, 0x00).unwrap();
+
+        let state = cpu.run(STACK_DEPTH + 4).unwrap();
+        assert_eq!(
+            Some("call stack overflow at 0x0200: exceeded 16 nested calls"),
+            state.fault()
+        );
+        assert_eq!(0x200, state.pc.read());
+    }
+
+    #[derive(Default)]
+    struct MockDisplay {
+        last_frame: Option<[bool; 2048]>,
+        frames: usize,
+    }
+
+    impl Chip8Display for MockDisplay {
+        fn draw(&mut self, framebuffer: &[bool; 2048]) {
+            self.last_frame = Some(*framebuffer);
+            self.frames += 1;
+        }
+    }
+
+    #[test]
+    fn should_draw_frames_to_the_renderer_after_drw_and_cls() {
+        let renderer = Rc::new(RefCell::new(MockDisplay::default()));
+        let mut cpu = Chip8::<()>::default().with_renderer(renderer.clone());
+        // LD I, 0x300; DRW V0, V0, 1; CLS
+        cpu.address_space
+            .load(0x200, &[0xa3, 0x00, 0xd0, 0x01, 0x00, 0xe0])
+            .unwrap();
+        cpu.address_space.write(0x300, 0b1000_0001).unwrap();
+
+        cpu.step().unwrap();
+        assert!(renderer.borrow().last_frame.is_none());
+
+        cpu.step().unwrap();
+        let frame = renderer.borrow().last_frame.unwrap();
+        assert!(frame[0]);
+        assert!(frame[7]);
+        assert_eq!(2, frame.iter().filter(|&&pixel| pixel).count());
+
+        cpu.step().unwrap();
+        let frame = renderer.borrow().last_frame.unwrap();
+        assert!(frame.iter().all(|&pixel| !pixel));
+    }
+
+    #[test]
+    fn should_draw_frames_to_the_renderer_when_run() {
+        let renderer = Rc::new(RefCell::new(MockDisplay::default()));
+        let mut cpu = Chip8::<()>::default().with_renderer(renderer.clone());
+        // LD I, 0x300; DRW V0, V0, 1; CLS
+        cpu.address_space
+            .load(0x200, &[0xa3, 0x00, 0xd0, 0x01, 0x00, 0xe0])
+            .unwrap();
+        cpu.address_space.write(0x300, 0b1000_0001).unwrap();
+
+        let state = cpu.run(2).unwrap();
+        assert_eq!(1, renderer.borrow().frames);
+        assert_eq!(
+            2,
+            renderer
+                .borrow()
+                .last_frame
+                .unwrap()
+                .iter()
+                .filter(|&&pixel| pixel)
+                .count()
+        );
+
+        state.run(1).unwrap();
+        assert_eq!(2, renderer.borrow().frames);
+        assert!(renderer
+            .borrow()
+            .last_frame
+            .unwrap()
+            .iter()
+            .all(|&pixel| !pixel));
+    }
+
+    #[test]
+    fn should_blank_the_display_on_cls() {
+        let mut cpu = Chip8::<()>::default().with_display(|display| {
+            display
+                .write_pixel(0, 0, true)
+                .0
+                .write_pixel(63, 31, true)
+                .0
+                .write_pixel(10, 20, true)
+                .0
+        });
+        assert!(cpu.framebuffer_packed().iter().any(|&byte| byte != 0));
+        // CLS
+        cpu.address_space.write(0x200, 0x00).unwrap();
+        cpu.address_space.write(0x201, 0xe0).unwrap();
+
+        let state = cpu.run(1).unwrap();
+        assert!(state.framebuffer_packed().iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn should_saturate_timers_at_zero_when_ticked() {
+        let mut cpu = Chip8::<()>::default()
+            .with_timer_register(
+                register::TimerRegisters::Delay,
+                register::ClockDecrementing::with_value(3),
+            )
+            .with_timer_register(
+                register::TimerRegisters::Sound,
+                register::ClockDecrementing::with_value(1),
+            );
+        assert!(cpu.sound_active());
+
+        cpu.tick_timers();
+        assert_eq!(2, cpu.dt.read());
+        assert!(!cpu.sound_active());
+
+        for _ in 0..5 {
+            cpu.tick_timers();
+        }
+        assert_eq!(0, cpu.dt.read());
+        assert_eq!(0, cpu.st.read());
+    }
+
+    #[test]
+    fn should_skip_next_instruction_on_se_immediate_only_when_equal() {
+        let mut cpu = Chip8::<()>::default().with_rng(|| 0u8).with_gp_register(
+            register::GpRegisters::V1,
+            register::GeneralPurpose::with_value(0x22),
+        );
+        cpu.address_space.write(0x200, 0x31).unwrap();
+        cpu.address_space.write(0x201, 0x22).unwrap();
+
+        let state = cpu.clone().run(1).unwrap();
+        assert_eq!(0x204, state.pc.read());
+
+        cpu.address_space.write(0x201, 0x23).unwrap();
+        let state = cpu.run(1).unwrap();
+        assert_eq!(0x202, state.pc.read());
+    }
+
+    #[test]
+    fn should_skip_next_instruction_on_sne_immediate_only_when_not_equal() {
+        let mut cpu = Chip8::<()>::default().with_rng(|| 0u8).with_gp_register(
+            register::GpRegisters::V1,
+            register::GeneralPurpose::with_value(0x22),
+        );
+        cpu.address_space.write(0x200, 0x41).unwrap();
+        cpu.address_space.write(0x201, 0x23).unwrap();
+
+        let state = cpu.clone().run(1).unwrap();
+        assert_eq!(0x204, state.pc.read());
+
+        cpu.address_space.write(0x201, 0x22).unwrap();
+        let state = cpu.run(1).unwrap();
+        assert_eq!(0x202, state.pc.read());
+    }
+
+    #[test]
+    fn should_skip_next_instruction_on_se_register_only_when_equal() {
+        let mut cpu = Chip8::<()>::default()
+            .with_rng(|| 0u8)
+            .with_gp_register(
+                register::GpRegisters::V1,
+                register::GeneralPurpose::with_value(0x22),
+            )
+            .with_gp_register(
+                register::GpRegisters::V2,
+                register::GeneralPurpose::with_value(0x22),
+            );
+        cpu.address_space.write(0x200, 0x51).unwrap();
+        cpu.address_space.write(0x201, 0x20).unwrap();
+
+        let state = cpu.clone().run(1).unwrap();
+        assert_eq!(0x204, state.pc.read());
+
+        // V3 holds 0x00 and doesn't match V1.
+        cpu.address_space.write(0x201, 0x30).unwrap();
+        let state = cpu.run(1).unwrap();
+        assert_eq!(0x202, state.pc.read());
+    }
+
+    #[test]
+    fn should_skip_next_instruction_on_sne_register_only_when_not_equal() {
+        let mut cpu = Chip8::<()>::default()
+            .with_rng(|| 0u8)
+            .with_gp_register(
+                register::GpRegisters::V1,
+                register::GeneralPurpose::with_value(0x22),
+            )
+            .with_gp_register(
+                register::GpRegisters::V2,
+                register::GeneralPurpose::with_value(0x22),
+            );
+        cpu.address_space.write(0x200, 0x91).unwrap();
+        cpu.address_space.write(0x201, 0x20).unwrap();
+
+        let state = cpu.clone().run(1).unwrap();
+        assert_eq!(0x202, state.pc.read());
+
+        // V3 holds 0x00 and doesn't match V1.
+        cpu.address_space.write(0x201, 0x30).unwrap();
+        let state = cpu.run(1).unwrap();
+        assert_eq!(0x204, state.pc.read());
+    }
+
+    #[test]
+    fn should_fill_audio_with_a_tone_only_while_the_sound_timer_is_active() {
+        let mut cpu = Chip8::<()>::default().with_rng(|| 0u8);
+        let mut out = [1.0f32; 256];
+
+        cpu.fill_audio(&mut out, 44100);
+        assert!(out.iter().all(|&sample| sample == 0.0));
+
+        let mut cpu = cpu.with_timer_register(
+            register::TimerRegisters::Sound,
+            register::ClockDecrementing::with_value(5),
+        );
+        cpu.fill_audio(&mut out, 44100);
+        assert!(out.iter().any(|&sample| sample != 0.0));
+        // a 440Hz tone at 44.1kHz flips sign roughly every 50 samples.
+        assert!(out[..50].iter().all(|&sample| sample > 0.0));
+        assert!(out[51..100].iter().all(|&sample| sample < 0.0));
+    }
+
+    #[test]
+    fn should_restore_initial_state_on_soft_reset_while_preserving_memory() {
+        let mut cpu = Chip8::<()>::default().with_rng(|| 0u8);
+        // ADD V0, 0x05; LD I, 0x123
+        for (addr, &value) in (0x200..).zip([0x70, 0x05, 0xa1, 0x23].iter()) {
+            cpu.address_space.write(addr, value).unwrap();
+        }
+
+        let mut state = cpu.run(2).unwrap();
+        assert_eq!(0x05, state.read_gp_register(register::GpRegisters::V0));
+        assert_eq!(0x123, state.i.read());
+        assert_eq!(0x204, state.pc.read());
+
+        state.soft_reset();
+        assert_eq!(0x00, state.read_gp_register(register::GpRegisters::V0));
+        assert_eq!(0x000, state.i.read());
+        assert_eq!(0x200, state.pc.read());
+        assert_eq!(0x70, state.address_space.read(0x200));
+        assert_eq!(0x23, state.address_space.read(0x203));
+    }
+
+    #[test]
+    fn should_seed_all_v_registers_at_once() {
+        let mut values = [0u8; 0x10];
+        for (idx, value) in values.iter_mut().enumerate() {
+            *value = idx as u8 * 0x11;
+        }
+        let cpu = Chip8::<()>::default().with_v_registers(values);
+
+        assert_eq!(0x00, cpu.read_gp_register(register::GpRegisters::V0));
+        assert_eq!(0x77, cpu.read_gp_register(register::GpRegisters::V7));
+        assert_eq!(0xff, cpu.read_gp_register(register::GpRegisters::Vf));
+
+        let mut cpu = cpu;
+        cpu.set_v_registers([0x42; 0x10]);
+        assert_eq!(0x42, cpu.read_gp_register(register::GpRegisters::Vf));
+    }
+
+    #[test]
+    fn should_leave_i_unchanged_after_loading_registers_with_chip48_quirks() {
+        let mut cpu = Chip8::<()>::default()
+            .with_rng(|| 0u8)
+            .with_quirks(Quirks::chip48())
+            .with_i_register(register::GeneralPurpose::with_value(0x300));
+        cpu.address_space.write(0x200, 0xf3).unwrap();
+        cpu.address_space.write(0x201, 0x65).unwrap();
+        for offset in 0..4 {
+            cpu.address_space
+                .write(0x300 + offset, offset as u8 + 1)
+                .unwrap();
+        }
+
+        let state = cpu.run(1).unwrap();
+        assert_eq!(0x300, state.i.read());
+        assert_eq!(0x04, state.read_gp_register(register::GpRegisters::V3));
+    }
+
+    #[test]
+    fn should_store_registers_through_vx_under_both_quirk_settings() {
+        for (quirks, expected_i) in
+            [(Quirks::chip48(), 0x300), (Quirks::cosmac_vip(), 0x303)].iter()
+        {
+            let mut cpu = Chip8::<()>::default()
+                .with_quirks(*quirks)
+                .with_i_register(register::GeneralPurpose::with_value(0x300))
+                .with_v_registers([0x01, 0x02, 0x03, 0x04, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+            // LD [I], V2
+            cpu.address_space.write(0x200, 0xf2).unwrap();
+            cpu.address_space.write(0x201, 0x55).unwrap();
+
+            let state = cpu.run(1).unwrap();
+            assert_eq!(*expected_i, state.i.read());
+            assert_eq!(
+                vec![0x01, 0x02, 0x03, 0x00],
+                (0x300..0x304)
+                    .map(|addr| state.address_space.read(addr))
+                    .collect::<Vec<u8>>()
+            );
+        }
+    }
+
+    #[test]
+    fn should_advance_i_after_loading_registers_with_cosmac_vip_quirks() {
+        let mut cpu = Chip8::<()>::default()
+            .with_rng(|| 0u8)
+            .with_quirks(Quirks::cosmac_vip())
+            .with_i_register(register::GeneralPurpose::with_value(0x300));
+        cpu.address_space.write(0x200, 0xf3).unwrap();
+        cpu.address_space.write(0x201, 0x65).unwrap();
+        for offset in 0..4 {
+            cpu.address_space
+                .write(0x300 + offset, offset as u8 + 1)
+                .unwrap();
+        }
+
+        let state = cpu.run(1).unwrap();
+        assert_eq!(0x304, state.i.read());
+        assert_eq!(0x04, state.read_gp_register(register::GpRegisters::V3));
+    }
+
+    #[test]
+    fn should_skip_on_skp_only_while_the_key_in_vx_is_held() {
+        let mut cpu = Chip8::<()>::default().with_gp_register(
+            register::GpRegisters::V1,
+            register::GeneralPurpose::with_value(0x0a),
+        );
+        // SKP V1 and SKNP V1
+        cpu.address_space.write(0x200, 0xe1).unwrap();
+        cpu.address_space.write(0x201, 0x9e).unwrap();
+        cpu.address_space.write(0x300, 0xe1).unwrap();
+        cpu.address_space.write(0x301, 0xa1).unwrap();
+
+        let held = cpu.clone().with_key(KeyInputValue::KeyA, true);
+        assert!(held.is_key_pressed(0x0a));
+        assert_eq!(0x204, held.clone().run(1).unwrap().pc.read());
+        let sknp_held = held.with_pc_register(register::ProgramCounter::with_value(0x300));
+        assert_eq!(0x302, sknp_held.run(1).unwrap().pc.read());
+
+        let released = cpu.with_key(KeyInputValue::KeyA, false);
+        assert!(!released.is_key_pressed(0x0a));
+        assert_eq!(0x202, released.clone().run(1).unwrap().pc.read());
+        let sknp_released = released.with_pc_register(register::ProgramCounter::with_value(0x300));
+        assert_eq!(0x304, sknp_released.run(1).unwrap().pc.read());
+    }
+
+    #[test]
+    fn should_wait_on_ld_k_until_a_key_is_held() {
+        let mut cpu = Chip8::<()>::default();
+        // LD V2, K
+        cpu.address_space.write(0x200, 0xf2).unwrap();
+        cpu.address_space.write(0x201, 0x0a).unwrap();
+
+        let waiting = cpu.run(3).unwrap();
+        assert_eq!(0x200, waiting.pc.read());
+        assert_eq!(0x00, waiting.read_gp_register(register::GpRegisters::V2));
+
+        let pressed = waiting.with_key(KeyInputValue::Key7, true).run(1).unwrap();
+        assert_eq!(0x202, pressed.pc.read());
+        assert_eq!(0x07, pressed.read_gp_register(register::GpRegisters::V2));
+    }
+
     #[test]
     fn should_clear_input_idempotently() {
         let cpu = Chip8::<()>::default()
@@ -623,6 +1652,36 @@ mod tests {
         assert_eq!(Some(false), cpu.display.pixel(1, 2));
     }
 
+    #[test]
+    fn should_pack_framebuffer_with_leftmost_pixel_in_most_significant_bit() {
+        let cpu = Chip8::<()>::default().with_display(|d| {
+            d.write_pixel(0, 0, true)
+                .0
+                .write_pixel(7, 0, true)
+                .0
+                .write_pixel(9, 1, true)
+                .0
+        });
+
+        let packed = cpu.framebuffer_packed();
+        assert_eq!(256, packed.len());
+        assert_eq!(0b1000_0001, packed[0]);
+        assert_eq!(0b0100_0000, packed[9]);
+        assert_eq!(2, packed.iter().filter(|&&byte| byte != 0).count());
+    }
+
+    #[test]
+    fn should_expand_framebuffer_to_rgba() {
+        let (on, off) = ([0xff, 0xff, 0xff, 0xff], [0x00, 0x00, 0x00, 0xff]);
+        let cpu = Chip8::<()>::default().with_display(|d| d.write_pixel(1, 0, true).0);
+
+        let rgba = cpu.framebuffer_rgba(on, off);
+        assert_eq!(64 * 32 * 4, rgba.len());
+        assert_eq!(off, rgba[0..4]);
+        assert_eq!(on, rgba[4..8]);
+        assert_eq!(off, rgba[8..12]);
+    }
+
     #[test]
     fn should_set_a_given_pixel_range_to_a_given_value() {
         let mut cpu = Chip8::<()>::default();
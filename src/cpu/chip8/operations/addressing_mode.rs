@@ -104,6 +104,33 @@ impl Default for VxVy {
     }
 }
 
+/// Represents an operation on a pair of registers holding a cartesian
+/// coordinate along with a nibble, such as a sprite height.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VxVyNibble {
+    pub x: register::GpRegisters,
+    pub y: register::GpRegisters,
+    pub nibble: u8,
+}
+
+impl AddressingMode for VxVyNibble {}
+
+impl VxVyNibble {
+    pub fn new(x: register::GpRegisters, y: register::GpRegisters, nibble: u8) -> Self {
+        Self { x, y, nibble }
+    }
+}
+
+impl Default for VxVyNibble {
+    fn default() -> Self {
+        Self {
+            x: register::GpRegisters::V0,
+            y: register::GpRegisters::V0,
+            nibble: 0,
+        }
+    }
+}
+
 /// Represents a register to register operation transfering a value from a
 /// register to the Sound Timer register.
 #[derive(Debug, Clone, Copy, PartialEq)]
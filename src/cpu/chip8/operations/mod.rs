@@ -167,15 +167,18 @@ where
             Jp<NonV0Indexed, Absolute>,
             Jp<V0Indexed, Absolute>,
             Ld<Absolute>,
+            Ld<Immediate>,
             Ld<VxVy>,
             Ld<SoundTimerDestTx>,
             Ld<DelayTimerDestTx>,
             Ld<DelayTimerSrcTx>,
             LdBcd<VxIIndirect>,
             LdK,
+            LdF,
             Add<Immediate>,
             Add<IRegisterIndexed>,
             Add<VxVy>,
+            Sub<VxVy>,
             Subn<VxVy>,
             And<VxVy>,
             Or<VxVy>,
@@ -184,15 +187,73 @@ where
             Xor<VxVy>,
             Se<VxVy>,
             Se<Immediate>,
+            Sne<Immediate>,
+            Sne<VxVy>,
             ReadRegistersFromMemory<VxIIndirect>,
             StoreRegistersToMemory<VxIIndirect>,
             Skp,
             Sknp,
+            Drw<VxVyNibble>,
         ))
         .parse(input)
     }
 }
 
+/// Describes a standard CHIP-8 opcode by its mnemonic and nibble pattern,
+/// where lowercase characters denote variable nibbles. i.e. `6xkk`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpcodePattern {
+    pub mnemonic: &'static str,
+    pub pattern: &'static str,
+}
+
+impl OpcodePattern {
+    pub const fn new(mnemonic: &'static str, pattern: &'static str) -> Self {
+        Self { mnemonic, pattern }
+    }
+}
+
+/// Returns the patterns of all standard opcodes that are currently decoded by
+/// the `OpcodeVariantParser`. This list should be kept in sync with the
+/// parser as opcodes are implemented.
+pub fn chip8_implemented_opcodes() -> Vec<OpcodePattern> {
+    vec![
+        OpcodePattern::new("CLS", "00E0"),
+        OpcodePattern::new("RET", "00EE"),
+        OpcodePattern::new("JP addr", "1nnn"),
+        OpcodePattern::new("CALL addr", "2nnn"),
+        OpcodePattern::new("SE Vx, byte", "3xkk"),
+        OpcodePattern::new("SNE Vx, byte", "4xkk"),
+        OpcodePattern::new("SE Vx, Vy", "5xy0"),
+        OpcodePattern::new("LD Vx, byte", "6xkk"),
+        OpcodePattern::new("ADD Vx, byte", "7xkk"),
+        OpcodePattern::new("LD Vx, Vy", "8xy0"),
+        OpcodePattern::new("OR Vx, Vy", "8xy1"),
+        OpcodePattern::new("AND Vx, Vy", "8xy2"),
+        OpcodePattern::new("XOR Vx, Vy", "8xy3"),
+        OpcodePattern::new("ADD Vx, Vy", "8xy4"),
+        OpcodePattern::new("SUB Vx, Vy", "8xy5"),
+        OpcodePattern::new("SHR Vx {, Vy}", "8xy6"),
+        OpcodePattern::new("SUBN Vx, Vy", "8xy7"),
+        OpcodePattern::new("SHL Vx {, Vy}", "8xyE"),
+        OpcodePattern::new("SNE Vx, Vy", "9xy0"),
+        OpcodePattern::new("LD I, addr", "Annn"),
+        OpcodePattern::new("JP V0, addr", "Bnnn"),
+        OpcodePattern::new("DRW Vx, Vy, nibble", "Dxyn"),
+        OpcodePattern::new("SKP Vx", "Ex9E"),
+        OpcodePattern::new("SKNP Vx", "ExA1"),
+        OpcodePattern::new("LD Vx, DT", "Fx07"),
+        OpcodePattern::new("LD Vx, K", "Fx0A"),
+        OpcodePattern::new("LD DT, Vx", "Fx15"),
+        OpcodePattern::new("LD ST, Vx", "Fx18"),
+        OpcodePattern::new("ADD I, Vx", "Fx1E"),
+        OpcodePattern::new("LD F, Vx", "Fx29"),
+        OpcodePattern::new("LD B, Vx", "Fx33"),
+        OpcodePattern::new("LD [I], Vx", "Fx55"),
+        OpcodePattern::new("LD Vx, [I]", "Fx65"),
+    ]
+}
+
 /// Clear the display.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct Cls;
@@ -321,9 +382,16 @@ impl<'a> parcel::Parser<'a, &'a [(usize, u8)], Jp<V0Indexed, addressing_mode::Ab
 
 impl<R> Generate<Chip8<R>, Vec<Microcode>> for Jp<V0Indexed, addressing_mode::Absolute> {
     fn generate(&self, cpu: &Chip8<R>) -> Vec<Microcode> {
-        let v0_val = cpu.read_gp_register(register::GpRegisters::V0);
         let abs_addr = self.addressing_mode.addr();
-        let jmp_addr = abs_addr.wrapping_add(u12::new(v0_val as u16));
+        // the CHIP-48 BXNN variant indexes by the register in the high nibble.
+        let offset_reg = if cpu.quirks.jump_with_offset_uses_vx {
+            let [reg_idx, _] = u16::from(abs_addr).to_be_bytes();
+            register::GpRegisters::try_from(reg_idx).expect(NIBBLE_OVERFLOW)
+        } else {
+            register::GpRegisters::V0
+        };
+        let offset = cpu.read_gp_register(offset_reg);
+        let jmp_addr = abs_addr.wrapping_add(u12::new(offset as u16));
 
         vec![Microcode::Write16bitRegister(Write16bitRegister::new(
             register::WordRegisters::ProgramCounter,
@@ -574,8 +642,8 @@ impl<'a> parcel::Parser<'a, &'a [(usize, u8)], LdBcd<addressing_mode::VxIIndirec
         expect_instruction_with_mask([
             NibbleMask::Fixed(0xF),
             NibbleMask::Variable,
-            NibbleMask::Fixed(0x1),
-            NibbleMask::Fixed(0x8),
+            NibbleMask::Fixed(0x3),
+            NibbleMask::Fixed(0x3),
         ])
         .map(|[_, reg_id, _, _]| {
             std::convert::TryFrom::<u8>::try_from(reg_id).expect(NIBBLE_OVERFLOW)
@@ -638,14 +706,18 @@ impl<'a> parcel::Parser<'a, &'a [(usize, u8)], LdK> for LdK {
 
 impl<R> Generate<Chip8<R>, Vec<Microcode>> for LdK {
     fn generate(&self, cpu: &Chip8<R>) -> Vec<Microcode> {
-        match cpu.interrupt {
+        // a latched keypress takes precedence over the lowest held key.
+        let key = match cpu.interrupt {
+            Some(chip8::Interrupt::KeyPress(key_input)) => Some(key_input as u8),
+            None => (0..0x10u8).find(|&key| cpu.is_key_pressed(key)),
+        };
+
+        match key {
             // if there is input set, write the input to a register.
-            Some(chip8::Interrupt::KeyPress(key_input)) => {
-                vec![Microcode::Write8bitRegister(Write8bitRegister::new(
-                    register::ByteRegisters::GpRegisters(self.dest),
-                    key_input as u8,
-                ))]
-            }
+            Some(key) => vec![Microcode::Write8bitRegister(Write8bitRegister::new(
+                register::ByteRegisters::GpRegisters(self.dest),
+                key,
+            ))],
             // if there is no input, default to looping on this instruction.
             None => vec![Microcode::Dec16bitRegister(Dec16bitRegister::new(
                 register::WordRegisters::ProgramCounter,
@@ -655,6 +727,64 @@ impl<R> Generate<Chip8<R>, Vec<Microcode>> for LdK {
     }
 }
 
+/// Set I to the address of the built-in font sprite for the hex digit in Vx.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LdF {
+    pub src: register::GpRegisters,
+}
+
+impl LdF {
+    pub fn new(src: register::GpRegisters) -> Self {
+        Self { src }
+    }
+}
+
+impl Default for LdF {
+    fn default() -> Self {
+        Self::new(register::GpRegisters::V0)
+    }
+}
+
+impl<'a> parcel::Parser<'a, &'a [(usize, u8)], LdF> for LdF {
+    fn parse(&self, input: &'a [(usize, u8)]) -> parcel::ParseResult<&'a [(usize, u8)], LdF> {
+        expect_instruction_with_mask([
+            NibbleMask::Fixed(0xF),
+            NibbleMask::Variable,
+            NibbleMask::Fixed(0x2),
+            NibbleMask::Fixed(0x9),
+        ])
+        .map(|[_, reg_id, _, _]| {
+            std::convert::TryFrom::<u8>::try_from(reg_id).expect(NIBBLE_OVERFLOW)
+        })
+        .map(LdF::new)
+        .parse(input)
+    }
+}
+
+impl<R> Generate<Chip8<R>, Vec<Microcode>> for LdF {
+    fn generate(&self, cpu: &Chip8<R>) -> Vec<Microcode> {
+        let digit = cpu.read_gp_register(self.src);
+
+        vec![Microcode::Write16bitRegister(Write16bitRegister::new(
+            register::WordRegisters::I,
+            chip8::font_sprite_addr(digit),
+        ))]
+    }
+}
+
+/// Returns the microcode to advance the I register past the last register
+/// accessed by Fx55 or Fx65 when the cpu's quirks call for it.
+fn load_store_i_increment<R>(cpu: &Chip8<R>, reg_inclusive_end_idx: u8) -> Option<Microcode> {
+    if cpu.quirks.load_store_increments_i {
+        Some(Microcode::Inc16bitRegister(Inc16bitRegister::new(
+            register::WordRegisters::I,
+            reg_inclusive_end_idx as u16 + 1,
+        )))
+    } else {
+        None
+    }
+}
+
 /// Represents the Load Indirect instruction to store a subset of registers at
 /// a memory offset defined by the contents of the I register.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
@@ -712,6 +842,7 @@ impl<R> Generate<Chip8<R>, Vec<Microcode>>
                     i_indirect_val,
                 ))
             })
+            .chain(load_store_i_increment(cpu, reg_inclusive_end_idx))
             .collect()
     }
 }
@@ -767,6 +898,7 @@ impl<R> Generate<Chip8<R>, Vec<Microcode>>
                 let i_idx = cpu.i.read() as u16 + reg as u16;
                 Microcode::WriteMemory(WriteMemory::new(i_idx, src_val))
             })
+            .chain(load_store_i_increment(cpu, reg_inclusive_end_idx))
             .collect()
     }
 }
@@ -805,13 +937,14 @@ impl<'a> parcel::Parser<'a, &'a [(usize, u8)], Call<addressing_mode::Absolute>>
 
 impl<R> Generate<Chip8<R>, Vec<Microcode>> for Call<addressing_mode::Absolute> {
     fn generate(&self, cpu: &Chip8<R>) -> Vec<Microcode> {
-        let current_pc = cpu.pc.read();
+        // return to the instruction following the call.
+        let return_pc = cpu.pc.read().wrapping_add(2);
         let addr = self.addressing_mode.addr();
         // decrement 2 to account for PC incrementing.
         let inc_adjusted_addr = u16::from(addr).wrapping_sub(2);
 
         vec![
-            Microcode::PushStack(PushStack::new(current_pc)),
+            Microcode::PushStack(PushStack::new(return_pc)),
             Microcode::Write16bitRegister(Write16bitRegister::new(
                 register::WordRegisters::ProgramCounter,
                 inc_adjusted_addr,
@@ -890,10 +1023,23 @@ impl<'a> parcel::Parser<'a, &'a [(usize, u8)], Add<addressing_mode::IRegisterInd
 impl<R> Generate<Chip8<R>, Vec<Microcode>> for Add<addressing_mode::IRegisterIndexed> {
     fn generate(&self, cpu: &Chip8<R>) -> Vec<Microcode> {
         let gp_val = cpu.read_gp_register(self.addressing_mode.register);
-        vec![Microcode::Inc16bitRegister(Inc16bitRegister::new(
+        let inc = Microcode::Inc16bitRegister(Inc16bitRegister::new(
             register::WordRegisters::I,
             gp_val as u16,
-        ))]
+        ));
+
+        if cpu.quirks.add_i_sets_vf_on_overflow {
+            let overflows = cpu.i.read() as u32 + gp_val as u32 > 0x0fff;
+            vec![
+                inc,
+                Microcode::Write8bitRegister(Write8bitRegister::new(
+                    register::ByteRegisters::GpRegisters(GpRegisters::Vf),
+                    overflows as u8,
+                )),
+            ]
+        } else {
+            vec![inc]
+        }
     }
 }
 
@@ -1057,6 +1203,34 @@ impl<R> Generate<Chip8<R>, Vec<Microcode>> for Subn<addressing_mode::VxVy> {
     }
 }
 
+/// Returns the microcode to store the result of a bitwise operation between
+/// Vx and Vy into Vx, resetting VF when the cpu's quirks call for it.
+fn bitwise_operation<R>(
+    cpu: &Chip8<R>,
+    addressing_mode: addressing_mode::VxVy,
+    op: fn(u8, u8) -> u8,
+) -> Vec<Microcode> {
+    let src_val = cpu.read_gp_register(addressing_mode.first);
+    let dest_val = cpu.read_gp_register(addressing_mode.second);
+
+    let result = Microcode::Write8bitRegister(Write8bitRegister::new(
+        register::ByteRegisters::GpRegisters(addressing_mode.second),
+        op(dest_val, src_val),
+    ));
+
+    if cpu.quirks.logic_resets_vf {
+        vec![
+            result,
+            Microcode::Write8bitRegister(Write8bitRegister::new(
+                register::ByteRegisters::GpRegisters(GpRegisters::Vf),
+                0,
+            )),
+        ]
+    } else {
+        vec![result]
+    }
+}
+
 /// And represents a binary & operation.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct And<A> {
@@ -1095,14 +1269,7 @@ impl<'a> parcel::Parser<'a, &'a [(usize, u8)], And<addressing_mode::VxVy>>
 
 impl<R> Generate<Chip8<R>, Vec<Microcode>> for And<addressing_mode::VxVy> {
     fn generate(&self, cpu: &Chip8<R>) -> Vec<Microcode> {
-        let src_val = cpu.read_gp_register(self.addressing_mode.first);
-        let dest_val = cpu.read_gp_register(self.addressing_mode.second);
-        let result = dest_val & src_val;
-
-        vec![Microcode::Write8bitRegister(Write8bitRegister::new(
-            register::ByteRegisters::GpRegisters(self.addressing_mode.second),
-            result,
-        ))]
+        bitwise_operation(cpu, self.addressing_mode, |dest, src| dest & src)
     }
 }
 
@@ -1144,14 +1311,7 @@ impl<'a> parcel::Parser<'a, &'a [(usize, u8)], Or<addressing_mode::VxVy>>
 
 impl<R> Generate<Chip8<R>, Vec<Microcode>> for Or<addressing_mode::VxVy> {
     fn generate(&self, cpu: &Chip8<R>) -> Vec<Microcode> {
-        let src_val = cpu.read_gp_register(self.addressing_mode.first);
-        let dest_val = cpu.read_gp_register(self.addressing_mode.second);
-        let result = dest_val | src_val;
-
-        vec![Microcode::Write8bitRegister(Write8bitRegister::new(
-            register::ByteRegisters::GpRegisters(self.addressing_mode.second),
-            result,
-        ))]
+        bitwise_operation(cpu, self.addressing_mode, |dest, src| dest | src)
     }
 }
 
@@ -1186,14 +1346,13 @@ impl<R> Generate<Chip8<R>, Vec<Microcode>> for Skp {
     fn generate(&self, cpu: &Chip8<R>) -> Vec<Microcode> {
         let reg_val = cpu.read_gp_register(self.register);
 
-        match cpu.interrupt {
-            Some(chip8::Interrupt::KeyPress(iv)) if iv as u8 == reg_val => {
-                vec![Microcode::Inc16bitRegister(Inc16bitRegister::new(
-                    register::WordRegisters::ProgramCounter,
-                    2,
-                ))]
-            }
-            _ => vec![],
+        if cpu.is_key_pressed(reg_val) {
+            vec![Microcode::Inc16bitRegister(Inc16bitRegister::new(
+                register::WordRegisters::ProgramCounter,
+                2,
+            ))]
+        } else {
+            vec![]
         }
     }
 }
@@ -1236,12 +1395,13 @@ impl<R> Generate<Chip8<R>, Vec<Microcode>> for Sknp {
     fn generate(&self, cpu: &Chip8<R>) -> Vec<Microcode> {
         let reg_val = cpu.read_gp_register(self.register);
 
-        match cpu.interrupt {
-            Some(chip8::Interrupt::KeyPress(iv)) if iv as u8 == reg_val => vec![],
-            _ => vec![Microcode::Inc16bitRegister(Inc16bitRegister::new(
+        if cpu.is_key_pressed(reg_val) {
+            vec![]
+        } else {
+            vec![Microcode::Inc16bitRegister(Inc16bitRegister::new(
                 register::WordRegisters::ProgramCounter,
                 2,
-            ))],
+            ))]
         }
     }
 }
@@ -1290,14 +1450,17 @@ impl<'a> parcel::Parser<'a, &'a [(usize, u8)], Xor<addressing_mode::VxVy>>
 
 impl<R> Generate<Chip8<R>, Vec<Microcode>> for Xor<addressing_mode::VxVy> {
     fn generate(&self, cpu: &Chip8<R>) -> Vec<Microcode> {
-        let src_val = cpu.read_gp_register(self.addressing_mode.first);
-        let dest_val = cpu.read_gp_register(self.addressing_mode.second);
-        let result = dest_val ^ src_val;
+        bitwise_operation(cpu, self.addressing_mode, |dest, src| dest ^ src)
+    }
+}
 
-        vec![Microcode::Write8bitRegister(Write8bitRegister::new(
-            register::ByteRegisters::GpRegisters(self.addressing_mode.second),
-            result,
-        ))]
+/// Returns the value to be shifted by 8xy6 and 8xyE, which is taken from Vy
+/// rather than Vx when the cpu's quirks call for it.
+fn shift_operand<R>(cpu: &Chip8<R>, addressing_mode: addressing_mode::VxVy) -> u8 {
+    if cpu.quirks.shift_uses_vy {
+        cpu.read_gp_register(addressing_mode.first)
+    } else {
+        cpu.read_gp_register(addressing_mode.second)
     }
 }
 
@@ -1339,7 +1502,7 @@ impl<'a> parcel::Parser<'a, &'a [(usize, u8)], Shl<addressing_mode::VxVy>>
 
 impl<R> Generate<Chip8<R>, Vec<Microcode>> for Shl<addressing_mode::VxVy> {
     fn generate(&self, cpu: &Chip8<R>) -> Vec<Microcode> {
-        let dest_val = cpu.read_gp_register(self.addressing_mode.second);
+        let dest_val = shift_operand(cpu, self.addressing_mode);
         // Set flags to 1 if MSB is 1
         let flags = dest_val >> 7;
         let result = dest_val << 1;
@@ -1396,7 +1559,7 @@ impl<'a> parcel::Parser<'a, &'a [(usize, u8)], Shr<addressing_mode::VxVy>>
 
 impl<R> Generate<Chip8<R>, Vec<Microcode>> for Shr<addressing_mode::VxVy> {
     fn generate(&self, cpu: &Chip8<R>) -> Vec<Microcode> {
-        let dest_val = cpu.read_gp_register(self.addressing_mode.second);
+        let dest_val = shift_operand(cpu, self.addressing_mode);
         // Set flags to 1 if LSB is 1
         let flags = dest_val & 1;
         let result = dest_val >> 1;
@@ -1646,3 +1809,78 @@ where
         ))]
     }
 }
+
+/// Drw XOR-draws an n-byte sprite, read from memory starting at I, to the
+/// display at the coordinates held in Vx and Vy. Pixels that fall past an
+/// edge of the display wrap around to the opposite edge. VF is set to 1 if any
+/// set pixel is erased, and 0 otherwise.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Drw<A> {
+    pub addressing_mode: A,
+}
+
+impl<A> Drw<A> {
+    pub fn new(addressing_mode: A) -> Self {
+        Self { addressing_mode }
+    }
+}
+
+impl<'a> parcel::Parser<'a, &'a [(usize, u8)], Drw<addressing_mode::VxVyNibble>>
+    for Drw<addressing_mode::VxVyNibble>
+{
+    fn parse(
+        &self,
+        input: &'a [(usize, u8)],
+    ) -> parcel::ParseResult<&'a [(usize, u8)], Drw<addressing_mode::VxVyNibble>> {
+        expect_instruction_with_mask([
+            NibbleMask::Fixed(0xD),
+            NibbleMask::Variable,
+            NibbleMask::Variable,
+            NibbleMask::Variable,
+        ])
+        .map(|[_, x, y, height]| {
+            let x_reg = std::convert::TryFrom::<u8>::try_from(x).expect(NIBBLE_OVERFLOW);
+            let y_reg = std::convert::TryFrom::<u8>::try_from(y).expect(NIBBLE_OVERFLOW);
+            addressing_mode::VxVyNibble::new(x_reg, y_reg, height)
+        })
+        .map(Drw::new)
+        .parse(input)
+    }
+}
+
+impl<R> Generate<Chip8<R>, Vec<Microcode>> for Drw<addressing_mode::VxVyNibble> {
+    fn generate(&self, cpu: &Chip8<R>) -> Vec<Microcode> {
+        use crate::address_map::Addressable;
+
+        let origin_x = cpu.read_gp_register(self.addressing_mode.x) as usize;
+        let origin_y = cpu.read_gp_register(self.addressing_mode.y) as usize;
+        let i = cpu.i.read();
+
+        let mut collision = false;
+        let mut mcs: Vec<Microcode> = (0..self.addressing_mode.nibble)
+            .flat_map(|row| {
+                let sprite_row = cpu.address_space.read(i.wrapping_add(u16::from(row)));
+                (0..8usize)
+                    .filter(move |bit| (sprite_row << bit) & 0x80 != 0)
+                    .map(move |bit| {
+                        (
+                            (origin_x + bit) % Display::x_max(),
+                            (origin_y + row as usize) % Display::y_max(),
+                        )
+                    })
+            })
+            .map(|(x, y)| {
+                // safe to unwrap as the coordinates are wrapped to the display.
+                let previous = cpu.display.pixel(x, y).unwrap();
+                collision |= previous;
+                Microcode::SetDisplayPixel(SetDisplayPixel::new((x, y), !previous))
+            })
+            .collect();
+
+        mcs.push(Microcode::Write8bitRegister(Write8bitRegister::new(
+            register::ByteRegisters::GpRegisters(GpRegisters::Vf),
+            collision as u8,
+        )));
+        mcs
+    }
+}
@@ -345,7 +345,7 @@ fn should_generate_load_byte_into_register_from_delay_timer_operation() {
 
 #[test]
 fn should_parse_load_bcd_from_vx_i_indirect_operation() {
-    let input: Vec<(usize, u8)> = 0xF818u16
+    let input: Vec<(usize, u8)> = 0xF833u16
         .to_be_bytes()
         .iter()
         .copied()
@@ -381,6 +381,15 @@ fn should_generate_load_bcd_from_vx_i_indirect_operation() {
     );
 }
 
+#[test]
+fn should_not_parse_load_sound_timer_as_load_bcd() {
+    let input = inst_to_enumerated_be_byte_vec(0xf818);
+    assert_eq!(
+        Ok(MatchStatus::NoMatch(&input[..])),
+        <LdBcd<addressing_mode::VxIIndirect>>::default().parse(&input[..])
+    );
+}
+
 #[test]
 fn should_parse_load_keypress_into_register_operation() {
     let input: Vec<(usize, u8)> = inst_to_enumerated_be_byte_vec(0xF80Au16);
@@ -548,15 +557,47 @@ fn should_generate_jump_absolute_indexed_by_v0_with_pc_incrementer() {
         register::GpRegisters::V0,
         register::GeneralPurpose::with_value(0x05),
     );
+    crate::tests::assert_generates(
+        Jp::<V0Indexed, addressing_mode::Absolute>::new(addressing_mode::Absolute::new(u12::new(
+            0x200,
+        ))),
+        &cpu,
+        vec![Microcode::Write16bitRegister(Write16bitRegister::new(
+            register::WordRegisters::ProgramCounter,
+            0x203,
+        ))],
+    );
+}
+
+#[test]
+fn should_generate_jump_indexed_by_v0_or_vx_depending_on_quirks() {
+    let jp = Jp::<V0Indexed, addressing_mode::Absolute>::new(addressing_mode::Absolute::new(
+        u12::new(0x320),
+    ));
+    let cpu = Chip8::<()>::default()
+        .with_rng(|| 0u8)
+        .with_gp_register(
+            register::GpRegisters::V0,
+            register::GeneralPurpose::with_value(0x05),
+        )
+        .with_gp_register(
+            register::GpRegisters::V3,
+            register::GeneralPurpose::with_value(0x10),
+        );
+
+    assert_eq!(
+        vec![Microcode::Write16bitRegister(Write16bitRegister::new(
+            register::WordRegisters::ProgramCounter,
+            0x323
+        ))],
+        jp.generate(&cpu.clone().with_quirks(chip8::Quirks::cosmac_vip()))
+    );
     assert_eq!(
         vec![Microcode::Write16bitRegister(Write16bitRegister::new(
             register::WordRegisters::ProgramCounter,
-            0x203
+            0x32e
         ))],
-        Jp::<V0Indexed, addressing_mode::Absolute>::new(addressing_mode::Absolute::new(u12::new(
-            0x200
-        )))
-        .generate(&cpu)
+        jp.generate(&cpu.with_quirks(chip8::Quirks::chip48()))
     );
 }
 
@@ -586,8 +627,8 @@ fn should_generate_call_absolute_instruction() {
 
     assert_eq!(
         vec![
-            // save initial value
-            Microcode::PushStack(PushStack::new(0x200)),
+            // save the return address
+            Microcode::PushStack(PushStack::new(0x202)),
             // jump to absolute value - 2.
             Microcode::Write16bitRegister(Write16bitRegister::new(
                 register::WordRegisters::ProgramCounter,
@@ -673,6 +714,37 @@ fn should_generate_add_i_register_indexed() {
     );
 }
 
+#[test]
+fn should_set_vf_on_i_overflow_only_with_the_amiga_quirk() {
+    let cpu = Chip8::<()>::default()
+        .with_i_register(register::GeneralPurpose::with_value(0x0ffe))
+        .with_gp_register(
+            register::GpRegisters::V5,
+            register::GeneralPurpose::<u8>::with_value(0x02),
+        );
+    let add = Add::new(addressing_mode::IRegisterIndexed::new(
+        register::GpRegisters::V5,
+    ));
+    let inc = Microcode::Inc16bitRegister(Inc16bitRegister::new(register::WordRegisters::I, 0x02));
+    let vf = |value| {
+        Microcode::Write8bitRegister(Write8bitRegister::new(
+            register::ByteRegisters::GpRegisters(GpRegisters::Vf),
+            value,
+        ))
+    };
+
+    assert_eq!(vec![inc], add.generate(&cpu));
+
+    let amiga = cpu.clone().with_quirks(chip8::Quirks {
+        add_i_sets_vf_on_overflow: true,
+        ..chip8::Quirks::default()
+    });
+    assert_eq!(vec![inc, vf(1)], add.generate(&amiga));
+
+    let below_boundary = amiga.with_i_register(register::GeneralPurpose::with_value(0x0ffd));
+    assert_eq!(vec![inc, vf(0)], add.generate(&below_boundary));
+}
+
 #[test]
 fn should_parse_add_vxvy_with_carry_operation() {
     let input: Vec<(usize, u8)> = 0x8014u16
@@ -821,6 +893,39 @@ fn should_generate_not_set_borrow_for_sub_vxvy_if_vx_value_is_larger_than_vy_val
     );
 }
 
+#[test]
+fn should_set_vf_when_sub_and_subn_operands_are_equal() {
+    let cpu = Chip8::<()>::default()
+        .with_rng(|| 0u8)
+        .with_gp_register(
+            register::GpRegisters::V0,
+            register::GeneralPurpose::<u8>::with_value(0x42),
+        )
+        .with_gp_register(
+            register::GpRegisters::V1,
+            register::GeneralPurpose::<u8>::with_value(0x42),
+        );
+    let expected = vec![
+        Microcode::Write8bitRegister(Write8bitRegister::new(
+            register::ByteRegisters::GpRegisters(GpRegisters::V0),
+            0x00,
+        )),
+        Microcode::Write8bitRegister(Write8bitRegister::new(
+            register::ByteRegisters::GpRegisters(GpRegisters::Vf),
+            0x01,
+        )),
+    ];
+
+    assert_eq!(
+        expected,
+        Sub::new(addressing_mode::VxVy::new(GpRegisters::V1, GpRegisters::V0)).generate(&cpu)
+    );
+    assert_eq!(
+        expected,
+        Subn::new(addressing_mode::VxVy::new(GpRegisters::V1, GpRegisters::V0)).generate(&cpu)
+    );
+}
+
 #[test]
 fn should_parse_subn_vxvy_without_borrow_operation() {
     let input: Vec<(usize, u8)> = 0x8017u16
@@ -943,6 +1048,66 @@ fn should_generate_and_byte_register_operation() {
     );
 }
 
+#[test]
+fn should_apply_bitwise_operations_with_known_bit_patterns() {
+    let cpu = Chip8::<()>::default()
+        .with_rng(|| 0u8)
+        .with_gp_register(
+            register::GpRegisters::V0,
+            register::GeneralPurpose::<u8>::with_value(0b1100_1100),
+        )
+        .with_gp_register(
+            register::GpRegisters::V1,
+            register::GeneralPurpose::<u8>::with_value(0b1010_1010),
+        );
+    let mode = addressing_mode::VxVy::new(register::GpRegisters::V1, register::GpRegisters::V0);
+    let write_v0 = |value| {
+        vec![Microcode::Write8bitRegister(Write8bitRegister::new(
+            register::ByteRegisters::GpRegisters(register::GpRegisters::V0),
+            value,
+        ))]
+    };
+
+    assert_eq!(write_v0(0b1110_1110), Or::new(mode).generate(&cpu));
+    assert_eq!(write_v0(0b1000_1000), And::new(mode).generate(&cpu));
+    assert_eq!(write_v0(0b0110_0110), Xor::new(mode).generate(&cpu));
+}
+
+#[test]
+fn should_reset_vf_on_bitwise_operations_only_with_cosmac_vip_quirks() {
+    let cpu = Chip8::<()>::default()
+        .with_gp_register(
+            register::GpRegisters::V0,
+            register::GeneralPurpose::<u8>::with_value(0b1100_1100),
+        )
+        .with_gp_register(
+            register::GpRegisters::V1,
+            register::GeneralPurpose::<u8>::with_value(0b1010_1010),
+        )
+        .with_gp_register(
+            register::GpRegisters::Vf,
+            register::GeneralPurpose::<u8>::with_value(0x01),
+        );
+    let mode = addressing_mode::VxVy::new(register::GpRegisters::V1, register::GpRegisters::V0);
+    let reset_vf = Microcode::Write8bitRegister(Write8bitRegister::new(
+        register::ByteRegisters::GpRegisters(register::GpRegisters::Vf),
+        0,
+    ));
+
+    let vip = cpu.clone().with_quirks(chip8::Quirks::cosmac_vip());
+    let chip48 = cpu.with_quirks(chip8::Quirks::chip48());
+    let ops: [Box<dyn Generate<Chip8<()>, Vec<Microcode>>>; 3] = [
+        Box::new(Or::new(mode)),
+        Box::new(And::new(mode)),
+        Box::new(Xor::new(mode)),
+    ];
+
+    for op in ops.iter() {
+        assert_eq!(Some(&reset_vf), op.generate(&vip).last());
+        assert!(!op.generate(&chip48).contains(&reset_vf));
+    }
+}
+
 #[test]
 fn should_parse_or_byte_register_operation_opcode() {
     let input: Vec<(usize, u8)> = 0x8011u16
@@ -1113,6 +1278,54 @@ fn should_generate_shr_vxvy_with_flag_operation_that_does_not_overflow_byte_capa
     );
 }
 
+#[test]
+fn should_shift_vx_or_vy_depending_on_quirks() {
+    let cpu = Chip8::<()>::default()
+        .with_gp_register(
+            register::GpRegisters::V0,
+            register::GeneralPurpose::<u8>::with_value(0b0100_0010),
+        )
+        .with_gp_register(
+            register::GpRegisters::V1,
+            register::GeneralPurpose::<u8>::with_value(0b1000_0001),
+        );
+    let mode = addressing_mode::VxVy::new(GpRegisters::V1, GpRegisters::V0);
+    let flag_and_result = |flag, result| {
+        vec![
+            Microcode::Write8bitRegister(Write8bitRegister::new(
+                register::ByteRegisters::GpRegisters(GpRegisters::Vf),
+                flag,
+            )),
+            Microcode::Write8bitRegister(Write8bitRegister::new(
+                register::ByteRegisters::GpRegisters(GpRegisters::V0),
+                result,
+            )),
+        ]
+    };
+
+    // shifting vx in place.
+    let chip48 = cpu.clone().with_quirks(chip8::Quirks::chip48());
+    assert_eq!(
+        flag_and_result(0, 0b1000_0100),
+        Shl::new(mode).generate(&chip48)
+    );
+    assert_eq!(
+        flag_and_result(0, 0b0010_0001),
+        Shr::new(mode).generate(&chip48)
+    );
+
+    // shifting vy into vx.
+    let vip = cpu.with_quirks(chip8::Quirks::cosmac_vip());
+    assert_eq!(
+        flag_and_result(1, 0b0000_0010),
+        Shl::new(mode).generate(&vip)
+    );
+    assert_eq!(
+        flag_and_result(1, 0b0100_0000),
+        Shr::new(mode).generate(&vip)
+    );
+}
+
 #[test]
 fn should_parse_xor_byte_register_operation_opcode() {
     let input: Vec<(usize, u8)> = 0x8013u16
@@ -1235,6 +1448,15 @@ fn should_parse_se_byte_register_operation_opcode() {
     );
 }
 
+#[test]
+fn should_not_parse_se_byte_register_operation_with_nonzero_final_nibble() {
+    let input = inst_to_enumerated_be_byte_vec(0x5011);
+    assert_eq!(
+        Ok(MatchStatus::NoMatch(&input[..])),
+        <Se<addressing_mode::VxVy>>::default().parse(&input[..])
+    );
+}
+
 #[test]
 fn should_generate_se_byte_register_operation() {
     let cpu_eq = Chip8::<()>::default()
@@ -1584,3 +1806,151 @@ fn should_generate_sknp_operation() {
         Sknp::new(register::GpRegisters::V0).generate(&cpu_none)
     );
 }
+
+#[test]
+fn should_parse_drw_opcode() {
+    let input = inst_to_enumerated_be_byte_vec(0xd125);
+    assert_eq!(
+        Ok(MatchStatus::Match {
+            span: 0..2,
+            remainder: &input[2..],
+            inner: Drw::new(addressing_mode::VxVyNibble::new(
+                register::GpRegisters::V1,
+                register::GpRegisters::V2,
+                5
+            ))
+        }),
+        <Drw<addressing_mode::VxVyNibble>>::default().parse(&input[..])
+    );
+}
+
+#[test]
+fn should_generate_only_a_vf_write_for_an_empty_sprite() {
+    let cpu = Chip8::<()>::default()
+        .with_i_register(register::GeneralPurpose::with_value(0x0))
+        .with_gp_register(
+            register::GpRegisters::V1,
+            register::GeneralPurpose::<u8>::with_value(0x3),
+        );
+
+    // address 0x000 is unset ROM, so a sprite can't be drawn from it.
+    assert_eq!(
+        vec![Microcode::Write8bitRegister(Write8bitRegister::new(
+            register::ByteRegisters::GpRegisters(GpRegisters::Vf),
+            0
+        ))],
+        Drw::new(addressing_mode::VxVyNibble::new(
+            register::GpRegisters::V1,
+            register::GpRegisters::V1,
+            1
+        ))
+        .generate(&cpu)
+    );
+}
+
+/// Representative encodings for each of the 35 standard CHIP-8 opcodes.
+const STANDARD_OPCODES: [(&str, u16); 35] = [
+    ("0nnn", 0x0123),
+    ("00E0", 0x00e0),
+    ("00EE", 0x00ee),
+    ("1nnn", 0x1234),
+    ("2nnn", 0x2345),
+    ("3xkk", 0x3122),
+    ("4xkk", 0x4122),
+    ("5xy0", 0x5120),
+    ("6xkk", 0x6122),
+    ("7xkk", 0x7122),
+    ("8xy0", 0x8120),
+    ("8xy1", 0x8121),
+    ("8xy2", 0x8122),
+    ("8xy3", 0x8123),
+    ("8xy4", 0x8124),
+    ("8xy5", 0x8125),
+    ("8xy6", 0x8126),
+    ("8xy7", 0x8127),
+    ("8xyE", 0x812e),
+    ("9xy0", 0x9120),
+    ("Annn", 0xa123),
+    ("Bnnn", 0xb123),
+    ("Cxkk", 0xc122),
+    ("Dxyn", 0xd125),
+    ("Ex9E", 0xe19e),
+    ("ExA1", 0xe1a1),
+    ("Fx07", 0xf107),
+    ("Fx0A", 0xf10a),
+    ("Fx15", 0xf115),
+    ("Fx18", 0xf118),
+    ("Fx1E", 0xf11e),
+    ("Fx29", 0xf129),
+    ("Fx33", 0xf133),
+    ("Fx55", 0xf155),
+    ("Fx65", 0xf165),
+];
+
+#[test]
+fn should_decode_only_the_opcodes_reported_as_implemented() {
+    let implemented: Vec<&str> = chip8::chip8_implemented_opcodes()
+        .into_iter()
+        .map(|opcode| opcode.pattern)
+        .collect();
+
+    let (handled, unimplemented): (Vec<_>, Vec<_>) =
+        STANDARD_OPCODES.iter().copied().partition(|(_, opcode)| {
+            let input = inst_to_enumerated_be_byte_vec(*opcode);
+            let res: parcel::ParseResult<_, Box<dyn Generate<Chip8<()>, Vec<Microcode>>>> =
+                OpcodeVariantParser.parse(&input[..]);
+            matches!(res, Ok(MatchStatus::Match { .. }))
+        });
+
+    assert_eq!(
+        implemented,
+        handled
+            .into_iter()
+            .map(|(pattern, _)| pattern)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec!["0nnn", "Cxkk"],
+        unimplemented
+            .into_iter()
+            .map(|(pattern, _)| pattern)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn should_generate_operation_specific_microcode_for_each_decoded_opcode() {
+    // each register holds its own index and the 0 key is pressed so that
+    // the samples below exercise the acting branch of conditional opcodes.
+    let cpu = (0..0xf)
+        .map(|idx| GpRegisters::try_from(idx).unwrap())
+        .fold(Chip8::<()>::default(), |cpu, reg| {
+            cpu.with_gp_register(reg, register::GeneralPurpose::with_value(reg as u8))
+        })
+        .with_interrupt(|| Some(chip8::Interrupt::KeyPress(chip8::KeyInputValue::Key0)));
+
+    let samples: [u16; 35] = [
+        0x0123, 0x00e0, 0x00ee, 0x1234, 0x2345, 0x3101, 0x4122, 0x5110, 0x6122, 0x7122, 0x8120,
+        0x8121, 0x8122, 0x8123, 0x8124, 0x8125, 0x8126, 0x8127, 0x812e, 0x9120, 0xa123, 0xb123,
+        0xc122, 0xd125, 0xe09e, 0xe1a1, 0xf107, 0xf10a, 0xf115, 0xf118, 0xf11e, 0xf129, 0xf133,
+        0xf155, 0xf165,
+    ];
+
+    let mut decoded = 0;
+    for opcode in samples.iter().copied() {
+        let input = inst_to_enumerated_be_byte_vec(opcode);
+        let res: parcel::ParseResult<_, Box<dyn Generate<Chip8<()>, Vec<Microcode>>>> =
+            OpcodeVariantParser.parse(&input[..]);
+
+        if let Ok(MatchStatus::Match { inner: op, .. }) = res {
+            decoded += 1;
+            assert!(
+                !op.generate(&cpu).is_empty(),
+                "no microcode generated for opcode: {:#06x}",
+                opcode
+            );
+        }
+    }
+
+    assert_eq!(chip8::chip8_implemented_opcodes().len(), decoded);
+}
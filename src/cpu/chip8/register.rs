@@ -67,8 +67,8 @@ impl std::convert::TryFrom<u8> for GpRegisters {
     }
 }
 
-/// Represents the special Timer registers that decrement at a pre-selected
-/// 60hz (1 second), clock-rate.
+/// Represents the special Timer registers that decrement toward zero at
+/// 60Hz.
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum TimerRegisters {
     Sound,
@@ -96,52 +96,37 @@ where
     }
 }
 
-/// Represents a one of the Special Timer registers that decrements at a set
-/// clockrate of 60Hz (1 second).
-#[derive(Debug, PartialEq, Clone, Copy)]
+/// Represents a one of the Special Timer registers that decrements toward
+/// zero once per tick. Ticks are driven by the caller at 60Hz, independent of
+/// the rate instructions are executed.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub struct ClockDecrementing {
-    written_at: std::time::Instant,
     inner: GeneralPurpose<u8>,
 }
 
+impl ClockDecrementing {
+    /// Returns the register decremented by one tick, saturating at zero.
+    pub fn tick(self) -> Self {
+        Self::with_value(self.inner.read().saturating_sub(1))
+    }
+}
+
 impl Register<u8, u8> for ClockDecrementing {
     fn read(&self) -> u8 {
-        let v = self.inner.read();
-        let seconds_since_write = self.written_at.elapsed().as_secs();
-
-        if v as u64 > seconds_since_write {
-            std::convert::TryInto::<u8>::try_into(seconds_since_write)
-                .map(|ssw| v - ssw)
-                .unwrap()
-        } else {
-            0
-        }
+        self.inner.read()
     }
 
     fn write(self, value: u8) -> Self {
-        Self {
-            written_at: std::time::Instant::now(),
-            inner: GeneralPurpose::with_value(value),
-        }
+        Self::with_value(value)
     }
 
     fn with_value(value: u8) -> Self {
         Self {
-            written_at: std::time::Instant::now(),
             inner: GeneralPurpose::with_value(value),
         }
     }
 }
 
-impl Default for ClockDecrementing {
-    fn default() -> Self {
-        Self {
-            written_at: std::time::Instant::now(),
-            inner: GeneralPurpose::default(),
-        }
-    }
-}
-
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub struct ProgramCounter {
     inner: u16,
@@ -200,11 +185,11 @@ mod tests {
     }
 
     #[test]
-    fn should_decrement_clocked_register_at_a_set_pulse() {
+    fn should_decrement_clocked_register_once_per_tick() {
         let cdr = ClockDecrementing::with_value(0xff);
-        let sleep_time = std::time::Duration::from_secs(2);
-        std::thread::sleep(sleep_time);
 
-        assert!(cdr.read() < 0xff)
+        assert_eq!(0xff, cdr.read());
+        assert_eq!(0xfe, cdr.tick().read());
+        assert_eq!(0xfd, cdr.tick().tick().read());
     }
 }
@@ -0,0 +1,323 @@
+use super::disassembler::{with_instruction, AddressingMode, AddressingModeType, Assembly};
+use super::operations::VariantParser;
+use isa_mos6502::{addressing_mode, mnemonic, Instruction, InstructionVariant};
+use parcel::Parser;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+/// Mnemonics that take a relative address operand.
+const BRANCH_MNEMONICS: [&str; 8] = ["BCC", "BCS", "BEQ", "BMI", "BNE", "BPL", "BVC", "BVS"];
+
+/// An operand as written in source, prior to address resolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Operand {
+    Byte(u8),
+    Word(u16),
+    Label(String),
+}
+
+/// A single assembled statement, prior to label resolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Statement {
+    Instruction {
+        opcode: u8,
+        addressing_mode: AddressingMode,
+        operand: Option<Operand>,
+    },
+    Bytes(Vec<u8>),
+}
+
+impl Statement {
+    fn size(&self) -> usize {
+        match self {
+            Statement::Instruction {
+                addressing_mode, ..
+            } => 1 + addressing_mode.operand_size(),
+            Statement::Bytes(bytes) => bytes.len(),
+        }
+    }
+}
+
+/// Returns the `AddressingMode` of an instruction's addressing mode type.
+fn addressing_mode_of<A: AddressingModeType>(_: &A) -> AddressingMode {
+    A::MODE
+}
+
+/// Builds a table of each mnemonic and addressing mode pair to its opcode by
+/// decoding every opcode with the instruction parser.
+fn opcode_table() -> HashMap<(String, AddressingMode), u8> {
+    (0..=0xffu8)
+        .filter_map(|opcode| match VariantParser.parse(&[opcode, 0, 0][..]) {
+            Ok(parcel::MatchStatus::Match { inner, .. }) => {
+                let key = with_instruction!(inner, |inst| (
+                    Assembly(inst.mnemonic).to_string(),
+                    addressing_mode_of(&inst.addressing_mode)
+                ));
+                Some((key, opcode))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Parses a `$` prefixed hex value into a byte or word operand, based on the
+/// number of digits provided, or a label.
+fn parse_value(value: &str) -> Result<Operand, String> {
+    match value.strip_prefix('$') {
+        Some(digits) if digits.len() <= 2 => u8::from_str_radix(digits, 16)
+            .map(Operand::Byte)
+            .map_err(|_| format!("invalid hex value {}", value)),
+        Some(digits) if digits.len() <= 4 => u16::from_str_radix(digits, 16)
+            .map(Operand::Word)
+            .map_err(|_| format!("invalid hex value {}", value)),
+        Some(_) => Err(format!("hex value {} exceeds 16 bits", value)),
+        None if !value.is_empty()
+            && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && !value.starts_with(|c: char| c.is_ascii_digit()) =>
+        {
+            Ok(Operand::Label(value.to_string()))
+        }
+        None => Err(format!("invalid operand {}", value)),
+    }
+}
+
+/// Parses an operand into its value and the addressing modes it could
+/// represent, in order of preference.
+fn parse_operand(
+    mnemonic: &str,
+    operand: &str,
+) -> Result<(Option<Operand>, Vec<AddressingMode>), String> {
+    let operand = normalize_operand(operand);
+
+    if operand.is_empty() {
+        return Ok((
+            None,
+            vec![AddressingMode::Implied, AddressingMode::Accumulator],
+        ));
+    } else if operand == "A" {
+        return Ok((None, vec![AddressingMode::Accumulator]));
+    } else if let Some(value) = operand.strip_prefix('#') {
+        return match parse_value(value)? {
+            Operand::Byte(byte) => Ok((Some(Operand::Byte(byte)), vec![AddressingMode::Immediate])),
+            _ => Err(format!("immediate value {} exceeds 8 bits", value)),
+        };
+    }
+
+    let (value, byte_modes, word_modes): (&str, Vec<AddressingMode>, Vec<AddressingMode>) =
+        if let Some(value) = operand
+            .strip_prefix('(')
+            .and_then(|v| v.strip_suffix(",X)"))
+        {
+            (value, vec![AddressingMode::XIndexedIndirect], vec![])
+        } else if let Some(value) = operand
+            .strip_prefix('(')
+            .and_then(|v| v.strip_suffix("),Y"))
+        {
+            (value, vec![AddressingMode::IndirectYIndexed], vec![])
+        } else if let Some(value) = operand.strip_prefix('(').and_then(|v| v.strip_suffix(')')) {
+            (value, vec![], vec![AddressingMode::Indirect])
+        } else if let Some(value) = operand.strip_suffix(",X") {
+            (
+                value,
+                vec![
+                    AddressingMode::ZeroPageIndexedWithX,
+                    AddressingMode::AbsoluteIndexedWithX,
+                ],
+                vec![AddressingMode::AbsoluteIndexedWithX],
+            )
+        } else if let Some(value) = operand.strip_suffix(",Y") {
+            (
+                value,
+                vec![
+                    AddressingMode::ZeroPageIndexedWithY,
+                    AddressingMode::AbsoluteIndexedWithY,
+                ],
+                vec![AddressingMode::AbsoluteIndexedWithY],
+            )
+        } else if BRANCH_MNEMONICS.contains(&mnemonic) {
+            (&operand, vec![AddressingMode::Relative], vec![])
+        } else {
+            (
+                &operand,
+                vec![AddressingMode::ZeroPage, AddressingMode::Absolute],
+                vec![AddressingMode::Absolute],
+            )
+        };
+
+    let value = parse_value(value)?;
+    let modes = match &value {
+        // branches resolve labels to a relative offset.
+        Operand::Label(_) if BRANCH_MNEMONICS.contains(&mnemonic) => byte_modes,
+        Operand::Byte(_) => byte_modes,
+        Operand::Word(_) | Operand::Label(_) => word_modes,
+    };
+
+    Ok((Some(value), modes))
+}
+
+/// Uppercases the accumulator and index register suffixes of an operand,
+/// leaving hex digits and labels as written.
+fn normalize_operand(operand: &str) -> String {
+    let operand = operand.replace(' ', "");
+    if operand.eq_ignore_ascii_case("a") {
+        return "A".to_string();
+    }
+
+    let lower = operand.to_ascii_lowercase();
+    [",x)", "),y", ",x", ",y"]
+        .iter()
+        .find(|suffix| lower.ends_with(*suffix))
+        .map(|suffix| {
+            format!(
+                "{}{}",
+                &operand[..operand.len() - suffix.len()],
+                suffix.to_uppercase()
+            )
+        })
+        .unwrap_or(operand)
+}
+
+/// Parses the comma separated values of a `.byte` directive.
+fn parse_bytes(values: &str) -> Result<Vec<u8>, String> {
+    values
+        .split(',')
+        .map(|value| match parse_value(value.trim())? {
+            Operand::Byte(byte) => Ok(byte),
+            _ => Err(format!("byte value {} exceeds 8 bits", value.trim())),
+        })
+        .collect()
+}
+
+/// Assembles source into machine code. Each line holds an optional label,
+/// suffixed with `:`, followed by an optional instruction or `.byte`
+/// directive. Operands are written in `$` prefixed hex, i.e. `LDA #$ff` or
+/// `STA $1000,X`, and comments begin with `;`.
+///
+/// Labels resolve to absolute addresses, or relative offsets for branches,
+/// with the program assembled starting at address `0x0000` unless a leading
+/// `.org` directive specifies otherwise.
+pub fn assemble(source: &str) -> Result<Vec<u8>, String> {
+    let opcodes = opcode_table();
+    let mut origin = 0u16;
+    let mut addr = 0usize;
+    let mut labels: HashMap<String, u16> = HashMap::new();
+    let mut statements: Vec<(usize, usize, Statement)> = Vec::new();
+
+    // first pass, records each label's address and the size of each
+    // statement.
+    for (line_idx, line) in source.lines().enumerate() {
+        let line_num = line_idx + 1;
+        let mut line = line.split(';').next().unwrap_or_default().trim();
+
+        if let Some((label, rest)) = line.split_once(':') {
+            let label = label.trim();
+            match parse_value(label) {
+                Ok(Operand::Label(_)) => (),
+                _ => return Err(format!("line {}: invalid label {}", line_num, label)),
+            }
+
+            let label_addr = origin.wrapping_add(addr as u16);
+            if labels.insert(label.to_string(), label_addr).is_some() {
+                return Err(format!("line {}: duplicate label {}", line_num, label));
+            }
+            line = rest.trim();
+        }
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let (mnemonic, operand) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let mnemonic = mnemonic.to_uppercase();
+        let operand = operand.trim();
+
+        let statement = match mnemonic.as_str() {
+            ".ORG" if statements.is_empty() => {
+                origin = match parse_value(operand) {
+                    Ok(Operand::Byte(byte)) => byte as u16,
+                    Ok(Operand::Word(word)) => word,
+                    _ => return Err(format!("line {}: invalid origin {}", line_num, operand)),
+                };
+                continue;
+            }
+            ".ORG" => {
+                return Err(format!(
+                    "line {}: .org must precede all instructions",
+                    line_num
+                ))
+            }
+            ".BYTE" => Statement::Bytes(
+                parse_bytes(operand).map_err(|e| format!("line {}: {}", line_num, e))?,
+            ),
+            _ => {
+                let (operand_value, modes) = parse_operand(&mnemonic, operand)
+                    .map_err(|e| format!("line {}: {}", line_num, e))?;
+                let (addressing_mode, opcode) = modes
+                    .into_iter()
+                    .find_map(|mode| {
+                        opcodes
+                            .get(&(mnemonic.clone(), mode))
+                            .map(|&opcode| (mode, opcode))
+                    })
+                    .ok_or_else(|| {
+                        format!(
+                            "line {}: invalid instruction {} {}",
+                            line_num, mnemonic, operand
+                        )
+                    })?;
+
+                Statement::Instruction {
+                    opcode,
+                    addressing_mode,
+                    operand: operand_value,
+                }
+            }
+        };
+
+        let size = statement.size();
+        statements.push((line_num, addr, statement));
+        addr += size;
+    }
+
+    // second pass, emits each statement with labels resolved.
+    let mut bytes = Vec::with_capacity(addr);
+    for (line_num, offset, statement) in statements {
+        let (opcode, addressing_mode, operand) = match statement {
+            Statement::Bytes(data) => {
+                bytes.extend(data);
+                continue;
+            }
+            Statement::Instruction {
+                opcode,
+                addressing_mode,
+                operand,
+            } => (opcode, addressing_mode, operand),
+        };
+
+        let value = match operand {
+            Some(Operand::Label(label)) => {
+                let target = *labels
+                    .get(&label)
+                    .ok_or_else(|| format!("line {}: undefined label {}", line_num, label))?;
+
+                if addressing_mode == AddressingMode::Relative {
+                    let next_addr = origin.wrapping_add(offset as u16).wrapping_add(2);
+                    let distance = target.wrapping_sub(next_addr) as i16;
+                    i8::try_from(distance).map_err(|_| {
+                        format!("line {}: branch to {} out of range", line_num, label)
+                    })? as u8 as u16
+                } else {
+                    target
+                }
+            }
+            Some(Operand::Byte(byte)) => byte as u16,
+            Some(Operand::Word(word)) => word,
+            None => 0,
+        };
+
+        bytes.push(opcode);
+        bytes.extend_from_slice(&value.to_le_bytes()[..addressing_mode.operand_size()]);
+    }
+
+    Ok(bytes)
+}
@@ -0,0 +1,74 @@
+use super::{
+    register::{ProgramStatusFlags, Registers},
+    Mos6502,
+};
+use crate::address_map::Addressable;
+use crate::cpu::register::Register;
+use std::ops::RangeInclusive;
+
+/// StateDiff represents a single point of divergence between two cpus,
+/// storing the value observed on each.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StateDiff {
+    Register {
+        register: Registers,
+        a: u16,
+        b: u16,
+    },
+    Flag {
+        flag: ProgramStatusFlags,
+        a: bool,
+        b: bool,
+    },
+    Memory {
+        address: u16,
+        a: u8,
+        b: u8,
+    },
+}
+
+/// Compares the registers, status flags and the provided range of memory of
+/// two cpus, returning each value that differs between them. The processor
+/// status register is reported by its individual flags.
+pub fn diff_state(a: &Mos6502, b: &Mos6502, memory: RangeInclusive<u16>) -> Vec<StateDiff> {
+    let registers = vec![
+        (Registers::Pc, a.pc.read(), b.pc.read()),
+        (Registers::Acc, a.acc.read() as u16, b.acc.read() as u16),
+        (Registers::X, a.x.read() as u16, b.x.read() as u16),
+        (Registers::Y, a.y.read() as u16, b.y.read() as u16),
+        (Registers::Sp, a.sp.read() as u16, b.sp.read() as u16),
+    ]
+    .into_iter()
+    .filter(|(_, a, b)| a != b)
+    .map(|(register, a, b)| StateDiff::Register { register, a, b });
+
+    let flags = vec![
+        (ProgramStatusFlags::Negative, a.ps.negative, b.ps.negative),
+        (ProgramStatusFlags::Overflow, a.ps.overflow, b.ps.overflow),
+        (ProgramStatusFlags::Break, a.ps.brk, b.ps.brk),
+        (ProgramStatusFlags::Decimal, a.ps.decimal, b.ps.decimal),
+        (
+            ProgramStatusFlags::Interrupt,
+            a.ps.interrupt_disable,
+            b.ps.interrupt_disable,
+        ),
+        (ProgramStatusFlags::Zero, a.ps.zero, b.ps.zero),
+        (ProgramStatusFlags::Carry, a.ps.carry, b.ps.carry),
+    ]
+    .into_iter()
+    .filter(|(_, a, b)| a != b)
+    .map(|(flag, a, b)| StateDiff::Flag { flag, a, b });
+
+    let memory = memory
+        .map(|address| {
+            (
+                address,
+                a.address_map.read(address),
+                b.address_map.read(address),
+            )
+        })
+        .filter(|(_, a, b)| a != b)
+        .map(|(address, a, b)| StateDiff::Memory { address, a, b });
+
+    registers.chain(flags).chain(memory).collect()
+}
@@ -0,0 +1,899 @@
+use super::{operations::VariantParser, Mos6502};
+use crate::address_map::Addressable;
+use crate::cpu::{register::Register, Offset};
+use isa_mos6502::{addressing_mode, mnemonic, Instruction, InstructionVariant};
+use parcel::Parser;
+use std::fmt;
+
+/// Assembly wraps an instruction, or one of its components, to render it in
+/// canonical assembly syntax, i.e. `LDA #$ff` or `STA $1000,X`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Assembly<T>(pub T);
+
+macro_rules! impl_mnemonic_assembly {
+    ($($mnemonic:ident),*) => {
+        $(
+            impl fmt::Display for Assembly<mnemonic::$mnemonic> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", stringify!($mnemonic).to_uppercase())
+                }
+            }
+        )*
+    };
+}
+
+impl_mnemonic_assembly!(
+    Adc, And, Asl, Bcc, Bcs, Beq, Bit, Bmi, Bne, Bpl, Brk, Bvc, Bvs, Clc, Cld, Cli, Clv, Cmp, Cpx,
+    Cpy, Dec, Dex, Dey, Eor, Inc, Inx, Iny, Jmp, Jsr, Lda, Ldx, Ldy, Lsr, Nop, Ora, Pha, Php, Pla,
+    Plp, Rol, Ror, Rti, Rts, Sbc, Sec, Sed, Sei, Sta, Stx, Sty, Tax, Tay, Tsx, Txa, Txs, Tya
+);
+
+/// AddressingMode identifies the addressing mode of an instruction apart from
+/// its operand, for looking up and sizing instructions by mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) enum AddressingMode {
+    Accumulator,
+    Implied,
+    Immediate,
+    ZeroPage,
+    ZeroPageIndexedWithX,
+    ZeroPageIndexedWithY,
+    XIndexedIndirect,
+    IndirectYIndexed,
+    Relative,
+    Absolute,
+    AbsoluteIndexedWithX,
+    AbsoluteIndexedWithY,
+    Indirect,
+}
+
+impl AddressingMode {
+    /// Returns the number of operand bytes taken by the addressing mode.
+    pub(super) fn operand_size(self) -> usize {
+        match self {
+            Self::Accumulator | Self::Implied => 0,
+            Self::Absolute
+            | Self::AbsoluteIndexedWithX
+            | Self::AbsoluteIndexedWithY
+            | Self::Indirect => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// Associates each of the isa's addressing mode types with its
+/// `AddressingMode`.
+pub(super) trait AddressingModeType {
+    const MODE: AddressingMode;
+}
+
+macro_rules! impl_addressing_mode_assembly {
+    ($($mode:ident => |$operand:ident| $fmt:expr),* $(,)?) => {
+        $(
+            impl AddressingModeType for addressing_mode::$mode {
+                const MODE: AddressingMode = AddressingMode::$mode;
+            }
+
+            impl fmt::Display for Assembly<addressing_mode::$mode> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let $operand = self.0;
+                    write!(f, "{}", $fmt)
+                }
+            }
+        )*
+    };
+}
+
+impl_addressing_mode_assembly!(
+    Accumulator => |_mode| "A",
+    Implied => |_mode| "",
+    Immediate => |mode| format!("#${:02x}", mode.unwrap()),
+    ZeroPage => |mode| format!("${:02x}", mode.unwrap()),
+    ZeroPageIndexedWithX => |mode| format!("${:02x},X", mode.unwrap()),
+    ZeroPageIndexedWithY => |mode| format!("${:02x},Y", mode.unwrap()),
+    XIndexedIndirect => |mode| format!("(${:02x},X)", mode.unwrap()),
+    IndirectYIndexed => |mode| format!("(${:02x}),Y", mode.unwrap()),
+    Relative => |mode| format!("${:02x}", mode.unwrap() as u8),
+    Absolute => |mode| format!("${:04x}", mode.unwrap()),
+    AbsoluteIndexedWithX => |mode| format!("${:04x},X", mode.unwrap()),
+    AbsoluteIndexedWithY => |mode| format!("${:04x},Y", mode.unwrap()),
+    Indirect => |mode| format!("(${:04x})", mode.unwrap()),
+);
+
+impl<M, A> fmt::Display for Assembly<Instruction<M, A>>
+where
+    M: Copy,
+    A: Copy,
+    Assembly<M>: fmt::Display,
+    Assembly<A>: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operand = Assembly(self.0.addressing_mode).to_string();
+        if operand.is_empty() {
+            write!(f, "{}", Assembly(self.0.mnemonic))
+        } else {
+            write!(f, "{} {}", Assembly(self.0.mnemonic), operand)
+        }
+    }
+}
+
+/// Matches an instruction variant, binding the typed instruction it wraps to
+/// `$inst` before evaluating `$body`. As each arm binds a differently typed
+/// instruction, `$body` is expanded once per variant. The isa types are
+/// resolved at the call site, which must import them.
+macro_rules! with_instruction {
+    ($variant:expr, |$inst:ident| $body:expr) => {
+        match $variant {
+            InstructionVariant::AdcAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Adc, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::AdcAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Adc, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::AdcAbsoluteIndexedWithY(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Adc, addressing_mode::AbsoluteIndexedWithY(am));
+                $body
+            }
+            InstructionVariant::AdcIndirectYIndexed(am) => {
+                let $inst = Instruction::new(mnemonic::Adc, addressing_mode::IndirectYIndexed(am));
+                $body
+            }
+            InstructionVariant::AdcImmediate(am) => {
+                let $inst = Instruction::new(mnemonic::Adc, addressing_mode::Immediate(am));
+                $body
+            }
+            InstructionVariant::AdcXIndexedIndirect(am) => {
+                let $inst = Instruction::new(mnemonic::Adc, addressing_mode::XIndexedIndirect(am));
+                $body
+            }
+            InstructionVariant::AdcZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Adc, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::AdcZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Adc, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::AndAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::And, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::AndAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::And, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::AndAbsoluteIndexedWithY(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::And, addressing_mode::AbsoluteIndexedWithY(am));
+                $body
+            }
+            InstructionVariant::AndIndirectYIndexed(am) => {
+                let $inst = Instruction::new(mnemonic::And, addressing_mode::IndirectYIndexed(am));
+                $body
+            }
+            InstructionVariant::AndImmediate(am) => {
+                let $inst = Instruction::new(mnemonic::And, addressing_mode::Immediate(am));
+                $body
+            }
+            InstructionVariant::AndXIndexedIndirect(am) => {
+                let $inst = Instruction::new(mnemonic::And, addressing_mode::XIndexedIndirect(am));
+                $body
+            }
+            InstructionVariant::AndZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::And, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::AndZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::And, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::AslAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Asl, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::AslAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Asl, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::AslAccumulator => {
+                let $inst = Instruction::new(mnemonic::Asl, addressing_mode::Accumulator);
+                $body
+            }
+            InstructionVariant::AslZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Asl, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::AslZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Asl, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::BccRelative(am) => {
+                let $inst = Instruction::new(mnemonic::Bcc, addressing_mode::Relative(am));
+                $body
+            }
+            InstructionVariant::BcsRelative(am) => {
+                let $inst = Instruction::new(mnemonic::Bcs, addressing_mode::Relative(am));
+                $body
+            }
+            InstructionVariant::BeqRelative(am) => {
+                let $inst = Instruction::new(mnemonic::Beq, addressing_mode::Relative(am));
+                $body
+            }
+            InstructionVariant::BmiRelative(am) => {
+                let $inst = Instruction::new(mnemonic::Bmi, addressing_mode::Relative(am));
+                $body
+            }
+            InstructionVariant::BitAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Bit, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::BitZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Bit, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::BneRelative(am) => {
+                let $inst = Instruction::new(mnemonic::Bne, addressing_mode::Relative(am));
+                $body
+            }
+            InstructionVariant::BplRelative(am) => {
+                let $inst = Instruction::new(mnemonic::Bpl, addressing_mode::Relative(am));
+                $body
+            }
+            InstructionVariant::BrkImplied => {
+                let $inst = Instruction::new(mnemonic::Brk, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::BvcRelative(am) => {
+                let $inst = Instruction::new(mnemonic::Bvc, addressing_mode::Relative(am));
+                $body
+            }
+            InstructionVariant::BvsRelative(am) => {
+                let $inst = Instruction::new(mnemonic::Bvs, addressing_mode::Relative(am));
+                $body
+            }
+            InstructionVariant::ClcImplied => {
+                let $inst = Instruction::new(mnemonic::Clc, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::CldImplied => {
+                let $inst = Instruction::new(mnemonic::Cld, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::CliImplied => {
+                let $inst = Instruction::new(mnemonic::Cli, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::ClvImplied => {
+                let $inst = Instruction::new(mnemonic::Clv, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::CmpAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Cmp, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::CmpAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Cmp, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::CmpAbsoluteIndexedWithY(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Cmp, addressing_mode::AbsoluteIndexedWithY(am));
+                $body
+            }
+            InstructionVariant::CmpIndirectYIndexed(am) => {
+                let $inst = Instruction::new(mnemonic::Cmp, addressing_mode::IndirectYIndexed(am));
+                $body
+            }
+            InstructionVariant::CmpImmediate(am) => {
+                let $inst = Instruction::new(mnemonic::Cmp, addressing_mode::Immediate(am));
+                $body
+            }
+            InstructionVariant::CmpXIndexedIndirect(am) => {
+                let $inst = Instruction::new(mnemonic::Cmp, addressing_mode::XIndexedIndirect(am));
+                $body
+            }
+            InstructionVariant::CmpZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Cmp, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::CmpZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Cmp, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::CpxAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Cpx, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::CpxImmediate(am) => {
+                let $inst = Instruction::new(mnemonic::Cpx, addressing_mode::Immediate(am));
+                $body
+            }
+            InstructionVariant::CpxZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Cpx, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::CpyAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Cpy, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::CpyImmediate(am) => {
+                let $inst = Instruction::new(mnemonic::Cpy, addressing_mode::Immediate(am));
+                $body
+            }
+            InstructionVariant::CpyZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Cpy, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::DecAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Dec, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::DecAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Dec, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::DecZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Dec, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::DecZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Dec, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::DexImplied => {
+                let $inst = Instruction::new(mnemonic::Dex, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::DeyImplied => {
+                let $inst = Instruction::new(mnemonic::Dey, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::EorAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Eor, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::EorAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Eor, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::EorAbsoluteIndexedWithY(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Eor, addressing_mode::AbsoluteIndexedWithY(am));
+                $body
+            }
+            InstructionVariant::EorIndirectYIndexed(am) => {
+                let $inst = Instruction::new(mnemonic::Eor, addressing_mode::IndirectYIndexed(am));
+                $body
+            }
+            InstructionVariant::EorImmediate(am) => {
+                let $inst = Instruction::new(mnemonic::Eor, addressing_mode::Immediate(am));
+                $body
+            }
+            InstructionVariant::EorXIndexedIndirect(am) => {
+                let $inst = Instruction::new(mnemonic::Eor, addressing_mode::XIndexedIndirect(am));
+                $body
+            }
+            InstructionVariant::EorZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Eor, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::EorZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Eor, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::IncAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Inc, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::IncAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Inc, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::IncZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Inc, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::IncZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Inc, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::InxImplied => {
+                let $inst = Instruction::new(mnemonic::Inx, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::InyImplied => {
+                let $inst = Instruction::new(mnemonic::Iny, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::JmpAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Jmp, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::JmpIndirect(am) => {
+                let $inst = Instruction::new(mnemonic::Jmp, addressing_mode::Indirect(am));
+                $body
+            }
+            InstructionVariant::JsrAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Jsr, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::LdaAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Lda, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::LdaAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Lda, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::LdaAbsoluteIndexedWithY(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Lda, addressing_mode::AbsoluteIndexedWithY(am));
+                $body
+            }
+            InstructionVariant::LdaIndirectYIndexed(am) => {
+                let $inst = Instruction::new(mnemonic::Lda, addressing_mode::IndirectYIndexed(am));
+                $body
+            }
+            InstructionVariant::LdaImmediate(am) => {
+                let $inst = Instruction::new(mnemonic::Lda, addressing_mode::Immediate(am));
+                $body
+            }
+            InstructionVariant::LdaXIndexedIndirect(am) => {
+                let $inst = Instruction::new(mnemonic::Lda, addressing_mode::XIndexedIndirect(am));
+                $body
+            }
+            InstructionVariant::LdaZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Lda, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::LdaZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Lda, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::LdxAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Ldx, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::LdxAbsoluteIndexedWithY(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Ldx, addressing_mode::AbsoluteIndexedWithY(am));
+                $body
+            }
+            InstructionVariant::LdxImmediate(am) => {
+                let $inst = Instruction::new(mnemonic::Ldx, addressing_mode::Immediate(am));
+                $body
+            }
+            InstructionVariant::LdxZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Ldx, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::LdxZeroPageIndexedWithY(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Ldx, addressing_mode::ZeroPageIndexedWithY(am));
+                $body
+            }
+            InstructionVariant::LdyAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Ldy, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::LdyAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Ldy, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::LdyImmediate(am) => {
+                let $inst = Instruction::new(mnemonic::Ldy, addressing_mode::Immediate(am));
+                $body
+            }
+            InstructionVariant::LdyZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Ldy, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::LdyZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Ldy, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::LsrAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Lsr, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::LsrAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Lsr, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::LsrAccumulator => {
+                let $inst = Instruction::new(mnemonic::Lsr, addressing_mode::Accumulator);
+                $body
+            }
+            InstructionVariant::LsrZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Lsr, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::LsrZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Lsr, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::NopImplied => {
+                let $inst = Instruction::new(mnemonic::Nop, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::OraAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Ora, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::OraAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Ora, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::OraAbsoluteIndexedWithY(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Ora, addressing_mode::AbsoluteIndexedWithY(am));
+                $body
+            }
+            InstructionVariant::OraIndirectYIndexed(am) => {
+                let $inst = Instruction::new(mnemonic::Ora, addressing_mode::IndirectYIndexed(am));
+                $body
+            }
+            InstructionVariant::OraImmediate(am) => {
+                let $inst = Instruction::new(mnemonic::Ora, addressing_mode::Immediate(am));
+                $body
+            }
+            InstructionVariant::OraXIndexedIndirect(am) => {
+                let $inst = Instruction::new(mnemonic::Ora, addressing_mode::XIndexedIndirect(am));
+                $body
+            }
+            InstructionVariant::OraZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Ora, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::OraZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Ora, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::PhaImplied => {
+                let $inst = Instruction::new(mnemonic::Pha, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::PhpImplied => {
+                let $inst = Instruction::new(mnemonic::Php, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::PlaImplied => {
+                let $inst = Instruction::new(mnemonic::Pla, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::PlpImplied => {
+                let $inst = Instruction::new(mnemonic::Plp, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::RolAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Rol, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::RolAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Rol, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::RolAccumulator => {
+                let $inst = Instruction::new(mnemonic::Rol, addressing_mode::Accumulator);
+                $body
+            }
+            InstructionVariant::RolZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Rol, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::RolZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Rol, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::RorAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Ror, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::RorAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Ror, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::RorAccumulator => {
+                let $inst = Instruction::new(mnemonic::Ror, addressing_mode::Accumulator);
+                $body
+            }
+            InstructionVariant::RorZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Ror, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::RorZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Ror, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::RtiImplied => {
+                let $inst = Instruction::new(mnemonic::Rti, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::RtsImplied => {
+                let $inst = Instruction::new(mnemonic::Rts, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::SbcAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Sbc, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::SbcAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Sbc, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::SbcAbsoluteIndexedWithY(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Sbc, addressing_mode::AbsoluteIndexedWithY(am));
+                $body
+            }
+            InstructionVariant::SbcIndirectYIndexed(am) => {
+                let $inst = Instruction::new(mnemonic::Sbc, addressing_mode::IndirectYIndexed(am));
+                $body
+            }
+            InstructionVariant::SbcImmediate(am) => {
+                let $inst = Instruction::new(mnemonic::Sbc, addressing_mode::Immediate(am));
+                $body
+            }
+            InstructionVariant::SbcXIndexedIndirect(am) => {
+                let $inst = Instruction::new(mnemonic::Sbc, addressing_mode::XIndexedIndirect(am));
+                $body
+            }
+            InstructionVariant::SbcZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Sbc, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::SbcZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Sbc, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::StaAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Sta, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::StaAbsoluteIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Sta, addressing_mode::AbsoluteIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::StaAbsoluteIndexedWithY(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Sta, addressing_mode::AbsoluteIndexedWithY(am));
+                $body
+            }
+            InstructionVariant::StaIndirectYIndexed(am) => {
+                let $inst = Instruction::new(mnemonic::Sta, addressing_mode::IndirectYIndexed(am));
+                $body
+            }
+            InstructionVariant::StaXIndexedIndirect(am) => {
+                let $inst = Instruction::new(mnemonic::Sta, addressing_mode::XIndexedIndirect(am));
+                $body
+            }
+            InstructionVariant::StaZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Sta, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::StaZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Sta, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::StxAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Stx, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::StxZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Stx, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::StxZeroPageIndexedWithY(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Stx, addressing_mode::ZeroPageIndexedWithY(am));
+                $body
+            }
+            InstructionVariant::StyAbsolute(am) => {
+                let $inst = Instruction::new(mnemonic::Sty, addressing_mode::Absolute(am));
+                $body
+            }
+            InstructionVariant::StyZeroPage(am) => {
+                let $inst = Instruction::new(mnemonic::Sty, addressing_mode::ZeroPage(am));
+                $body
+            }
+            InstructionVariant::StyZeroPageIndexedWithX(am) => {
+                let $inst =
+                    Instruction::new(mnemonic::Sty, addressing_mode::ZeroPageIndexedWithX(am));
+                $body
+            }
+            InstructionVariant::SecImplied => {
+                let $inst = Instruction::new(mnemonic::Sec, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::SedImplied => {
+                let $inst = Instruction::new(mnemonic::Sed, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::SeiImplied => {
+                let $inst = Instruction::new(mnemonic::Sei, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::TaxImplied => {
+                let $inst = Instruction::new(mnemonic::Tax, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::TayImplied => {
+                let $inst = Instruction::new(mnemonic::Tay, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::TsxImplied => {
+                let $inst = Instruction::new(mnemonic::Tsx, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::TxaImplied => {
+                let $inst = Instruction::new(mnemonic::Txa, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::TxsImplied => {
+                let $inst = Instruction::new(mnemonic::Txs, addressing_mode::Implied);
+                $body
+            }
+            InstructionVariant::TyaImplied => {
+                let $inst = Instruction::new(mnemonic::Tya, addressing_mode::Implied);
+                $body
+            }
+        }
+    };
+}
+
+pub(super) use with_instruction;
+
+impl fmt::Display for Assembly<InstructionVariant> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        with_instruction!(self.0, |inst| Assembly(inst).fmt(f))
+    }
+}
+
+/// Disassembles the instruction at the head of the provided bytes, returning
+/// its length and assembly representation. Bytes that do not decode to an
+/// instruction are represented as a single `.byte` directive.
+pub fn disassemble_instruction(bytes: &[u8]) -> (usize, String) {
+    match VariantParser.parse(bytes) {
+        Ok(parcel::MatchStatus::Match { inner, .. }) => {
+            (inner.offset(), Assembly(inner).to_string())
+        }
+        _ => (
+            1,
+            format!(".byte ${:02x}", bytes.first().copied().unwrap_or(0)),
+        ),
+    }
+}
+
+/// Disassembles a stream of bytes, returning the offset of each instruction
+/// from the start of the stream along with its assembly representation.
+/// Trailing bytes that do not form a complete instruction are represented as
+/// `.byte` directives. As offsets are addresses within the 16-bit address
+/// space, only the first 64KiB of the stream is disassembled.
+pub fn disassemble(bytes: &[u8]) -> Vec<(u16, String)> {
+    let bytes = &bytes[..bytes.len().min(0x10000)];
+    let mut insts = Vec::new();
+    let mut offset = 0;
+
+    while offset < bytes.len() {
+        // pad the remaining bytes to catch instructions that run past the end
+        // of the stream.
+        let remaining = &bytes[offset..];
+        let mut padded = remaining.to_vec();
+        padded.resize(remaining.len().max(MAX_INSTRUCTION_LEN), 0);
+
+        let (len, asm) = disassemble_instruction(&padded);
+        if len > remaining.len() {
+            insts.extend(
+                remaining
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, byte)| ((offset + idx) as u16, format!(".byte ${:02x}", byte))),
+            );
+            break;
+        }
+
+        insts.push((offset as u16, asm));
+        offset += len;
+    }
+
+    insts
+}
+
+/// The maximum length of a single instruction.
+pub(super) const MAX_INSTRUCTION_LEN: usize = 3;
+
+impl<B: Addressable<u16, u8>> Mos6502<B> {
+    /// Disassembles a window of instructions around the program counter,
+    /// returning the address and assembly of each along with whether it is
+    /// the instruction at the program counter.
+    ///
+    /// As instructions are variable length, the instructions preceding the
+    /// program counter are found by scanning back from the furthest address
+    /// they could start at and picking the earliest alignment that decodes
+    /// onto the program counter. Fewer than `before` instructions may be
+    /// returned if no such alignment is found.
+    pub fn disassemble_around_pc(&self, before: usize, after: usize) -> Vec<(u16, String, bool)> {
+        let pc = self.pc.read();
+        let max_scan = (before * MAX_INSTRUCTION_LEN) as u16;
+
+        let preceding = (1..=max_scan)
+            .rev()
+            .filter_map(|distance| pc.checked_sub(distance))
+            .map(|start| self.disassemble_range(start, |addr, _| addr < pc))
+            .find(|insts| {
+                insts
+                    .last()
+                    .is_some_and(|(addr, len, _)| addr.wrapping_add(*len as u16) == pc)
+            })
+            .unwrap_or_default();
+        let skip = preceding.len().saturating_sub(before);
+
+        let following = self.disassemble_range(pc, |_, count| count <= after);
+
+        preceding
+            .into_iter()
+            .skip(skip)
+            .chain(following)
+            .map(|(addr, _, asm)| (addr, asm, addr == pc))
+            .collect()
+    }
+
+    /// Disassembles instructions from the start address while the predicate,
+    /// called with the address and count of instructions decoded so far,
+    /// holds.
+    fn disassemble_range<F>(&self, start: u16, predicate: F) -> Vec<(u16, usize, String)>
+    where
+        F: Fn(u16, usize) -> bool,
+    {
+        let mut insts = Vec::new();
+        let mut addr = start;
+
+        while predicate(addr, insts.len()) {
+            let bytes: Vec<u8> = (0..MAX_INSTRUCTION_LEN as u16)
+                .map(|offset| self.address_map.read(addr.wrapping_add(offset)))
+                .collect();
+            let (len, asm) = disassemble_instruction(&bytes);
+            insts.push((addr, len, asm));
+
+            match addr.checked_add(len as u16) {
+                Some(next) => addr = next,
+                None => break,
+            }
+        }
+
+        insts
+    }
+}
@@ -0,0 +1,62 @@
+use super::{CpuSnapshot, MemoryRegion, Mos6502};
+use crate::address_map::memory::FlatMemory;
+
+/// FlatCpu represents a 6502 backed by a single flat 64KiB RAM, for the
+/// common case where no peripherals or ROM need to be mapped. Memory is
+/// stored inline on the cpu rather than behind an address map, while the
+/// instruction engine, and with it the full step and run api, is shared with
+/// `Mos6502`.
+pub type FlatCpu = Mos6502<FlatMemory>;
+
+impl FlatCpu {
+    /// Instantiates a FlatCpu with the provided memory spanning the entire
+    /// address space.
+    pub fn with_memory(memory: FlatMemory) -> Self {
+        Self::with_addressmap(memory)
+    }
+
+    /// Captures the registers, cycle count and memory of the cpu, with the
+    /// memory captured as a single region spanning the address space.
+    pub fn snapshot(&self) -> CpuSnapshot {
+        CpuSnapshot {
+            acc: self.acc,
+            x: self.x,
+            y: self.y,
+            sp: self.sp,
+            pc: self.pc,
+            ps: self.ps,
+            cycles: self.cycles,
+            memory: vec![MemoryRegion {
+                start: 0x0000,
+                bytes: self.address_map.dump(),
+            }],
+        }
+    }
+}
+
+/// Serializes a FlatCpu as its snapshot. Callbacks and configuration aren't
+/// serializable and are left out.
+#[cfg(feature = "serde")]
+impl serde::Serialize for FlatCpu {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.snapshot().serialize(serializer)
+    }
+}
+
+/// Deserializes a FlatCpu from a snapshot, restoring it onto zeroed memory.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FlatCpu {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let snapshot = CpuSnapshot::deserialize(deserializer)?;
+        let mut cpu = FlatCpu::with_memory(FlatMemory::new());
+        cpu.restore(&snapshot).map_err(serde::de::Error::custom)?;
+
+        Ok(cpu)
+    }
+}
@@ -9,6 +9,7 @@ use crate::cpu::mos6502::register::{ByteRegisters, ProgramStatusFlags, WordRegis
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Microcode {
     WriteMemory(WriteMemory),
+    DummyWriteMemory(DummyWriteMemory),
     SetProgramStatusFlagState(SetProgramStatusFlagState),
     Write8bitRegister(Write8bitRegister),
     Inc8bitRegister(Inc8bitRegister),
@@ -18,6 +19,93 @@ pub enum Microcode {
     Dec16bitRegister(Dec16bitRegister),
 }
 
+/// Renders microcode to a compact trace format, i.e. `W $0200=42` for a
+/// memory write, `R8 ACC=42` for a register write or `PC+=3` for an
+/// increment. All values are represented in hexadecimal.
+impl std::fmt::Display for Microcode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Microcode::WriteMemory(mc) => write!(f, "W ${:04x}={:02x}", mc.address, mc.value),
+            Microcode::DummyWriteMemory(mc) => {
+                write!(f, "DW ${:04x}={:02x}", mc.address, mc.value)
+            }
+            Microcode::SetProgramStatusFlagState(mc) => {
+                write!(f, "F {}={}", mc.flag, mc.value as u8)
+            }
+            Microcode::Write8bitRegister(mc) => write!(f, "R8 {}={:02x}", mc.register, mc.value),
+            Microcode::Inc8bitRegister(mc) => write!(f, "{}+={:x}", mc.register, mc.value),
+            Microcode::Dec8bitRegister(mc) => write!(f, "{}-={:x}", mc.register, mc.value),
+            Microcode::Write16bitRegister(mc) => {
+                write!(f, "R16 {}={:04x}", mc.register, mc.value)
+            }
+            Microcode::Inc16bitRegister(mc) => write!(f, "{}+={:x}", mc.register, mc.value),
+            Microcode::Dec16bitRegister(mc) => write!(f, "{}-={:x}", mc.register, mc.value),
+        }
+    }
+}
+
+/// Parses microcode from the trace format produced by its `Display`
+/// implementation.
+impl std::str::FromStr for Microcode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid microcode: {}", s);
+        let parse_u8 = |v: &str| u8::from_str_radix(v, 16).map_err(|_| invalid());
+        let parse_u16 = |v: &str| u16::from_str_radix(v, 16).map_err(|_| invalid());
+
+        if let Some((op, operands)) = s.split_once(' ') {
+            let (lhs, rhs) = operands.split_once('=').ok_or_else(invalid)?;
+            match op {
+                "W" => Ok(Microcode::WriteMemory(WriteMemory::new(
+                    parse_u16(lhs.strip_prefix('$').ok_or_else(invalid)?)?,
+                    parse_u8(rhs)?,
+                ))),
+                "DW" => Ok(Microcode::DummyWriteMemory(DummyWriteMemory::new(
+                    parse_u16(lhs.strip_prefix('$').ok_or_else(invalid)?)?,
+                    parse_u8(rhs)?,
+                ))),
+                "F" => Ok(Microcode::SetProgramStatusFlagState(
+                    SetProgramStatusFlagState::new(lhs.parse()?, parse_u8(rhs)? != 0),
+                )),
+                "R8" => Ok(Microcode::Write8bitRegister(Write8bitRegister::new(
+                    lhs.parse()?,
+                    parse_u8(rhs)?,
+                ))),
+                "R16" => Ok(Microcode::Write16bitRegister(Write16bitRegister::new(
+                    lhs.parse()?,
+                    parse_u16(rhs)?,
+                ))),
+                _ => Err(invalid()),
+            }
+        } else if let Some((reg, value)) = s.split_once("+=") {
+            match reg.parse::<WordRegisters>() {
+                Ok(reg) => Ok(Microcode::Inc16bitRegister(Inc16bitRegister::new(
+                    reg,
+                    parse_u16(value)?,
+                ))),
+                Err(_) => Ok(Microcode::Inc8bitRegister(Inc8bitRegister::new(
+                    reg.parse()?,
+                    parse_u8(value)?,
+                ))),
+            }
+        } else if let Some((reg, value)) = s.split_once("-=") {
+            match reg.parse::<WordRegisters>() {
+                Ok(reg) => Ok(Microcode::Dec16bitRegister(Dec16bitRegister::new(
+                    reg,
+                    parse_u16(value)?,
+                ))),
+                Err(_) => Ok(Microcode::Dec8bitRegister(Dec8bitRegister::new(
+                    reg.parse()?,
+                    parse_u8(value)?,
+                ))),
+            }
+        } else {
+            Err(invalid())
+        }
+    }
+}
+
 /// Represents a write of the value to the memory location specified by the
 /// address field.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
@@ -32,6 +120,21 @@ impl WriteMemory {
     }
 }
 
+/// Represents the write of an unmodified value back to the memory location
+/// specified by the address field, as performed by read-modify-write
+/// instructions on hardware prior to writing the modified value.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct DummyWriteMemory {
+    pub address: u16,
+    pub value: u8,
+}
+
+impl DummyWriteMemory {
+    pub fn new(address: u16, value: u8) -> Self {
+        Self { address, value }
+    }
+}
+
 /// Represents a write of the value to the memory location specified by the
 /// address field.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -143,6 +246,15 @@ macro_rules! gen_write_memory_microcode {
     };
 }
 
+#[allow(unused_macros)]
+macro_rules! gen_dummy_write_memory_microcode {
+    ($addr:expr, $value:expr) => {
+        $crate::cpu::mos6502::microcode::Microcode::DummyWriteMemory(
+            $crate::cpu::mos6502::microcode::DummyWriteMemory::new($addr, $value),
+        )
+    };
+}
+
 #[allow(unused_macros)]
 macro_rules! gen_flag_set_microcode {
     ($flag:expr, $value:expr) => {
@@ -205,3 +317,58 @@ macro_rules! gen_dec_16bit_register_microcode {
         )
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_render_microcode_to_trace_format() {
+        assert_eq!(
+            "W $0200=42",
+            Microcode::WriteMemory(WriteMemory::new(0x0200, 0x42)).to_string()
+        );
+        assert_eq!(
+            "R8 ACC=42",
+            Microcode::Write8bitRegister(Write8bitRegister::new(ByteRegisters::Acc, 0x42))
+                .to_string()
+        );
+        assert_eq!(
+            "PC+=3",
+            Microcode::Inc16bitRegister(Inc16bitRegister::new(WordRegisters::Pc, 3)).to_string()
+        );
+    }
+
+    #[test]
+    fn should_round_trip_each_microcode_variant_through_trace_format() {
+        let microcode = vec![
+            Microcode::WriteMemory(WriteMemory::new(0x0200, 0x42)),
+            Microcode::DummyWriteMemory(DummyWriteMemory::new(0x01ff, 0x00)),
+            Microcode::SetProgramStatusFlagState(SetProgramStatusFlagState::new(
+                ProgramStatusFlags::Overflow,
+                true,
+            )),
+            Microcode::SetProgramStatusFlagState(SetProgramStatusFlagState::new(
+                ProgramStatusFlags::Carry,
+                false,
+            )),
+            Microcode::Write8bitRegister(Write8bitRegister::new(ByteRegisters::Ps, 0xff)),
+            Microcode::Inc8bitRegister(Inc8bitRegister::new(ByteRegisters::X, 0x01)),
+            Microcode::Dec8bitRegister(Dec8bitRegister::new(ByteRegisters::Sp, 0x02)),
+            Microcode::Write16bitRegister(Write16bitRegister::new(WordRegisters::Pc, 0xfffc)),
+            Microcode::Inc16bitRegister(Inc16bitRegister::new(WordRegisters::Pc, 0x03)),
+            Microcode::Dec16bitRegister(Dec16bitRegister::new(WordRegisters::Pc, 0x100)),
+        ];
+
+        for mc in microcode {
+            assert_eq!(Ok(mc), mc.to_string().parse::<Microcode>());
+        }
+    }
+
+    #[test]
+    fn should_fail_to_parse_malformed_trace_format() {
+        assert!("W 0200=42".parse::<Microcode>().is_err());
+        assert!("R8 Q=42".parse::<Microcode>().is_err());
+        assert!("PC*=3".parse::<Microcode>().is_err());
+    }
+}
@@ -1,12 +1,16 @@
-use std::ops::RangeInclusive;
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::{Range, RangeInclusive};
+use std::rc::Rc;
 
 use crate::{
     address_map::{
         memory::{Memory, ReadOnly, ReadWrite},
         AddressMap, Addressable,
     },
-    cpu::{register::Register, Cpu, Execute, ExecuteMut, Generate, StepState},
+    cpu::{register::Register, Cpu, Cyclable, ExecuteMut, Generate, Offset, StepState},
 };
+use isa_mos6502::InstructionVariant;
 
 #[macro_use]
 pub mod microcode;
@@ -22,6 +26,9 @@ pub const RESET_VECTOR_HH: u16 = 0xfffd;
 pub const IRQ_VECTOR_LL: u16 = 0xfffe;
 pub const IRQ_VECTOR_HH: u16 = 0xffff;
 
+/// The stack pointer value left by the reset sequence.
+pub const RESET_STACK_POINTER: u8 = 0xfd;
+
 pub mod register;
 use parcel::Parser;
 use register::{
@@ -31,6 +38,27 @@ use register::{
 
 pub mod operations;
 
+mod diff;
+pub use diff::{diff_state, StateDiff};
+
+mod flat;
+pub use flat::FlatCpu;
+
+mod snapshot;
+pub use snapshot::{CpuSnapshot, MemoryRegion};
+
+mod trace;
+pub use trace::TraceLine;
+
+mod assembler;
+pub use assembler::assemble;
+
+mod disassembler;
+pub use disassembler::{disassemble, disassemble_instruction, Assembly};
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+
 /// Provides an alias for the 16bit addressed RW stack.
 pub type StackMemory = Memory<ReadWrite, u16, u8>;
 
@@ -40,16 +68,119 @@ pub type Ram = Memory<ReadWrite, u16, u8>;
 /// Provides an alias for the 16bit addressable RO ROM.
 pub type Rom = Memory<ReadOnly, u16, u8>;
 
-/// Mos6502 represents the 6502 CPU
+/// Callback wraps a user-provided hook so that it can be cloned and
+/// debug-printed along with the cpu it is registered on.
+pub struct Callback<F: ?Sized> {
+    inner: Rc<F>,
+}
+
+impl<F: ?Sized> Clone for Callback<F> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<F: ?Sized> fmt::Debug for Callback<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Callback")
+    }
+}
+
+/// A callback invoked with the program counter along with the stack pointer
+/// before and after a push or pull wraps the stack.
+pub type StackGuardHandler = Callback<dyn Fn(u16, u8, u8)>;
+
+/// A callback invoked with the program counter when an instruction is fetched
+/// from outside all executable regions.
+pub type EscapeHandler = Callback<dyn Fn(u16)>;
+
+/// A callback invoked once per elapsed cycle with the running cycle count.
+pub type CycleCallback = Callback<dyn Fn(usize)>;
+
+/// A callback invoked with a trace of each instruction before it is stepped.
+pub type TraceCallback = Callback<dyn Fn(&TraceLine)>;
+
+/// The number of cycles taken to service an IRQ or NMI.
+pub const INTERRUPT_CYCLES: usize = 7;
+
+/// Variant represents the revision of the 6502 being emulated, for the
+/// behaviors that differ between them.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Variant {
+    /// The original NMOS 6502.
+    #[default]
+    Nmos,
+    /// The CMOS 65C02.
+    Cmos,
+}
+
+/// StepError represents a failure to execute an instruction.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepError {
+    /// The opcode at `pc` doesn't decode to an instruction.
+    Decode { opcode: u8, pc: u16 },
+    /// A store to `addr` was rejected by the bus.
+    Write { addr: u16, reason: String },
+}
+
+impl fmt::Display for StepError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StepError::Decode { opcode, pc } => {
+                write!(f, "unable to decode opcode {:#04x} at {:#06x}", opcode, pc)
+            }
+            StepError::Write { addr, reason } => {
+                write!(f, "write to {:#06x} failed: {}", addr, reason)
+            }
+        }
+    }
+}
+
+/// StopReason records why a call to `run` halted before exhausting its
+/// cycle budget.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StopReason {
+    /// The program counter reached the configured success trap.
+    SuccessTrap,
+    /// The program counter reached the configured failure trap.
+    FailureTrap,
+    /// A store to the configured result address, carrying the value stored.
+    ResultTrap(u8),
+    /// An instruction failed to execute.
+    Fault(StepError),
+}
+
+/// Mos6502 represents the 6502 CPU, generic over the bus it is attached to.
+/// By default this is an `AddressMap`, allowing arbitrary addressables to be
+/// mapped into the address space.
 #[derive(Debug, Clone)]
-pub struct Mos6502 {
-    address_map: AddressMap<u16, u8>,
+pub struct Mos6502<B = AddressMap<u16, u8>> {
+    address_map: B,
     pub acc: GeneralPurpose,
     pub x: GeneralPurpose,
     pub y: GeneralPurpose,
     pub sp: StackPointer,
     pub pc: ProgramCounter,
     pub ps: ProcessorStatus,
+    variant: Variant,
+    illegal_opcodes: bool,
+    fast_mode: bool,
+    cycle_accurate: bool,
+    stack_guard_enabled: bool,
+    stack_guard: Option<StackGuardHandler>,
+    executable_regions: Vec<Range<u16>>,
+    escape_handler: Option<EscapeHandler>,
+    cycles: usize,
+    cycle_callback: Option<CycleCallback>,
+    trace_callback: Option<TraceCallback>,
+    success_trap: Option<u16>,
+    failure_trap: Option<u16>,
+    result_trap: Option<u16>,
+    result_stored: Option<u8>,
+    stop_reason: Option<StopReason>,
+    in_flight: VecDeque<Vec<microcode::Microcode>>,
 }
 
 impl Mos6502 {
@@ -57,14 +188,6 @@ impl Mos6502 {
         Self::default()
     }
 
-    /// instantiates a new Mos6502 with a provided address_map.
-    #[allow(clippy::field_reassign_with_default)]
-    pub fn with_addressmap(am: AddressMap<u16, u8>) -> Self {
-        let mut cpu = Self::default();
-        cpu.address_map = am;
-        cpu
-    }
-
     /// Functions as a wrapper around the `with_addressmap` and `register`
     /// methods in a way that conforms to the builder pattern and facilitates
     /// chainability of the registration. As such this method _can_ fail and
@@ -101,36 +224,74 @@ impl Mos6502 {
 
         Ok(self)
     }
+}
 
-    /// Simulates the reset process of the CPU.
-    pub fn reset(self) -> StepState<Self> {
-        let mut cpu = Mos6502::with_addressmap(self.address_map);
-        let lsb: u8 = cpu.address_map.read(RESET_VECTOR_LL);
-        let msb: u8 = cpu.address_map.read(RESET_VECTOR_HH);
+impl<B: Addressable<u16, u8>> Mos6502<B> {
+    /// instantiates a new Mos6502 with a provided address_map, or any other
+    /// bus spanning the address space.
+    pub fn with_addressmap(am: B) -> Self {
+        Self {
+            address_map: am,
+            acc: GeneralPurpose::default(),
+            x: GeneralPurpose::default(),
+            y: GeneralPurpose::default(),
+            sp: StackPointer::default(),
+            pc: ProgramCounter::default(),
+            ps: ProcessorStatus::default(),
+            variant: Variant::default(),
+            illegal_opcodes: false,
+            fast_mode: false,
+            cycle_accurate: false,
+            stack_guard_enabled: false,
+            stack_guard: None,
+            executable_regions: Vec::new(),
+            escape_handler: None,
+            cycles: 0,
+            cycle_callback: None,
+            trace_callback: None,
+            success_trap: None,
+            failure_trap: None,
+            result_trap: None,
+            result_stored: None,
+            stop_reason: None,
+            in_flight: VecDeque::new(),
+        }
+    }
 
-        cpu.pc = ProgramCounter::default().write(u16::from_le_bytes([lsb, msb]));
-        cpu.sp = StackPointer::default();
-        StepState::new(6, cpu)
+    /// Simulates the reset process of the CPU. Registers and the cycle count
+    /// are cleared while the bus and configuration are retained.
+    pub fn reset(mut self) -> StepState<Self> {
+        self.acc = GeneralPurpose::default();
+        self.x = GeneralPurpose::default();
+        self.y = GeneralPurpose::default();
+        self.ps = ProcessorStatus::default();
+        self.cycles = 0;
+        self.stop_reason = None;
+        self.result_stored = None;
+        self.in_flight.clear();
+
+        // the reset sequence performs three suppressed stack pushes, leaving
+        // the stack pointer at 0xfd, and masks interrupts.
+        self.pc = ProgramCounter::default().write(self.address_map.read_u16(RESET_VECTOR_LL));
+        self.sp = StackPointer::with_value(RESET_STACK_POINTER);
+        self.ps.interrupt_disable = true;
+        StepState::new(6, self)
     }
 
     /// Simulates the reset process of the CPU, exporting the options as a Operations type
     pub fn reset_as_mops(&self) -> operations::Operations {
-        let lsb: u8 = self.address_map.read(RESET_VECTOR_LL);
-        let msb: u8 = self.address_map.read(RESET_VECTOR_HH);
-        let pc = ProgramCounter::default().write(u16::from_le_bytes([lsb, msb]));
+        let pc = ProgramCounter::default().write(self.address_map.read_u16(RESET_VECTOR_LL));
 
         operations::Operations::new(
             0,
             6,
             vec![
-                gen_write_8bit_register_microcode!(
-                    ByteRegisters::Ps,
-                    ProcessorStatus::default().read()
-                ),
-                gen_write_8bit_register_microcode!(
-                    ByteRegisters::Sp,
-                    StackPointer::default().read()
-                ),
+                gen_write_8bit_register_microcode!(ByteRegisters::Ps, {
+                    let mut ps = ProcessorStatus::default();
+                    ps.interrupt_disable = true;
+                    ps.read()
+                }),
+                gen_write_8bit_register_microcode!(ByteRegisters::Sp, RESET_STACK_POINTER),
                 gen_write_16bit_register_microcode!(WordRegisters::Pc, pc.read()),
             ],
         )
@@ -167,92 +328,483 @@ impl Mos6502 {
         self.ps = reg;
         self
     }
+
+    /// Sets the revision of the 6502 to emulate.
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Enables or disables decoding of the undocumented NMOS opcodes that are
+    /// supported.
+    pub fn with_illegal_opcodes(mut self, enabled: bool) -> Self {
+        self.illegal_opcodes = enabled;
+        self
+    }
+
+    /// Enables or disables fast mode. When enabled, page-crossing and
+    /// branch-taken penalties are skipped and every instruction costs its base
+    /// cycles, trading timing accuracy for throughput.
+    pub fn with_fast_mode(mut self, enabled: bool) -> Self {
+        self.fast_mode = enabled;
+        self
+    }
+
+    /// Enables or disables cycle-accurate bus behavior. When enabled,
+    /// read-modify-write instructions write the unmodified value back to
+    /// memory before writing the modified value, as the hardware does.
+    pub fn with_cycle_accurate(mut self, enabled: bool) -> Self {
+        self.cycle_accurate = enabled;
+        self
+    }
+
+    /// Enables or disables detection of the stack pointer wrapping past 0x00
+    /// or 0xff during a push or pull.
+    pub fn set_stack_guard(&mut self, enabled: bool) {
+        self.stack_guard_enabled = enabled;
+    }
+
+    /// Sets the callback that is invoked with the program counter and the
+    /// stack pointer before and after a wrap, when the stack guard is enabled.
+    pub fn set_stack_guard_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(u16, u8, u8) + 'static,
+    {
+        self.stack_guard = Some(Callback {
+            inner: Rc::new(handler),
+        });
+    }
+
+    /// Defines the address ranges the program counter is expected to execute
+    /// from. An empty set of regions disables the check.
+    pub fn set_executable_regions(&mut self, regions: Vec<Range<u16>>) {
+        self.executable_regions = regions;
+    }
+
+    /// Sets the callback that is invoked with the program counter when an
+    /// instruction is fetched from outside all executable regions.
+    pub fn set_executable_region_escape_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(u16) + 'static,
+    {
+        self.escape_handler = Some(Callback {
+            inner: Rc::new(handler),
+        });
+    }
+
+    /// Sets the callback that is invoked with a trace of each instruction,
+    /// prior to its execution, by `step`, `run_for` and `run`.
+    pub fn set_trace_callback<F>(&mut self, handler: F)
+    where
+        F: Fn(&TraceLine) + 'static,
+    {
+        self.trace_callback = Some(Callback {
+            inner: Rc::new(handler),
+        });
+    }
+
+    /// Sets the callback that is invoked once per elapsed cycle, including
+    /// the cycles spent servicing an interrupt.
+    pub fn set_cycle_callback<F>(&mut self, handler: F)
+    where
+        F: Fn(usize) + 'static,
+    {
+        self.cycle_callback = Some(Callback {
+            inner: Rc::new(handler),
+        });
+    }
+
+    /// Returns the number of cycles elapsed since the cpu was last reset.
+    pub fn cycles(&self) -> usize {
+        self.cycles
+    }
+
+    /// Resets the elapsed cycle count to zero without otherwise affecting the
+    /// cpu.
+    pub fn reset_cycle_counter(&mut self) {
+        self.cycles = 0;
+    }
+
+    /// Sets an address that halts `run` with `StopReason::SuccessTrap` once
+    /// the program counter reaches it.
+    pub fn set_success_trap(&mut self, addr: u16) {
+        self.success_trap = Some(addr);
+    }
+
+    /// Sets an address that halts `run` with `StopReason::FailureTrap` once
+    /// the program counter reaches it.
+    pub fn set_failure_trap(&mut self, addr: u16) {
+        self.failure_trap = Some(addr);
+    }
+
+    /// Sets an address that halts `run` with `StopReason::ResultTrap` once an
+    /// instruction that stores to it completes.
+    pub fn set_result_trap(&mut self, addr: u16) {
+        self.result_trap = Some(addr);
+    }
+
+    /// Returns the reason the last call to `run` halted early, if it stopped
+    /// on a trap or a fault.
+    pub fn stop_reason(&self) -> Option<StopReason> {
+        self.stop_reason.clone()
+    }
+
+    /// Returns the trap, if any, to halt on at the current instruction
+    /// boundary.
+    fn pending_trap(&self) -> Option<StopReason> {
+        let pc = self.pc.read();
+        if let Some(value) = self.result_stored {
+            Some(StopReason::ResultTrap(value))
+        } else if self.success_trap == Some(pc) {
+            Some(StopReason::SuccessTrap)
+        } else if self.failure_trap == Some(pc) {
+            Some(StopReason::FailureTrap)
+        } else {
+            None
+        }
+    }
+
+    /// Simulates a maskable interrupt request. The request is ignored while
+    /// the interrupt disable flag is set.
+    pub fn irq(self) -> StepState<Self> {
+        if self.ps.interrupt_disable {
+            StepState::new(0, self)
+        } else {
+            self.interrupt(IRQ_VECTOR_LL)
+        }
+    }
+
+    /// Simulates a non-maskable interrupt.
+    pub fn nmi(self) -> StepState<Self> {
+        self.interrupt(NMI_VECTOR_LL)
+    }
+
+    /// Pushes the program counter and status to the stack, masks further
+    /// interrupts and loads the program counter from the provided vector,
+    /// accounting for the 7 cycles the sequence takes. An instruction left in
+    /// flight by `run` is completed first, as interrupts are only taken at
+    /// instruction boundaries.
+    fn interrupt(mut self, vector: u16) -> StepState<Self> {
+        let completed = match self.complete_in_flight() {
+            Ok(cycles) => cycles,
+            Err(e) => {
+                self.stop_reason = Some(StopReason::Fault(e));
+                0
+            }
+        };
+
+        // the break flag is only set on the stack by a BRK.
+        let mut status = self.ps;
+        status.brk = false;
+
+        let sp = self.sp.read();
+        let [pcl, pch] = self.pc.read().to_le_bytes();
+        let vector = self.address_map.read_u16(vector);
+
+        let mcs = [
+            gen_write_memory_microcode!(u16::from_le_bytes([sp, 0x01]), pch),
+            gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
+            gen_write_memory_microcode!(u16::from_le_bytes([sp.wrapping_sub(1), 0x01]), pcl),
+            gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
+            gen_write_memory_microcode!(
+                u16::from_le_bytes([sp.wrapping_sub(2), 0x01]),
+                status.read()
+            ),
+            gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
+            gen_flag_set_microcode!(ProgramStatusFlags::Interrupt, true),
+            gen_write_16bit_register_microcode!(WordRegisters::Pc, vector),
+        ];
+        for mc in mcs.iter() {
+            self.execute_mut(mc)
+        }
+
+        self.tick(INTERRUPT_CYCLES);
+        StepState::new(completed + INTERRUPT_CYCLES, self)
+    }
+
+    /// Executes the remaining cycles of an instruction left in flight by
+    /// `run`, returning the number of cycles it consumed. On a failed write
+    /// the remainder of the instruction is abandoned.
+    fn complete_in_flight(&mut self) -> Result<usize, StepError> {
+        let cycles = self.in_flight.len();
+        while let Some(cycle) = self.in_flight.pop_front() {
+            if let Err(e) = self.execute_cycle(&cycle) {
+                self.in_flight.clear();
+                return Err(e);
+            }
+        }
+        self.tick(cycles);
+
+        Ok(cycles)
+    }
+
+    /// Executes the microcode of a single cycle, stopping at the first write
+    /// that the bus rejects.
+    fn execute_cycle(&mut self, cycle: &[microcode::Microcode]) -> Result<(), StepError> {
+        for mc in cycle {
+            match mc {
+                microcode::Microcode::WriteMemory(mc) => self.write_memory(mc.address, mc.value)?,
+                microcode::Microcode::DummyWriteMemory(mc) => {
+                    self.write_memory(mc.address, mc.value)?
+                }
+                mc => self.execute_mut(mc),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes a value to the bus, recording a store to the result trap.
+    fn write_memory(&mut self, addr: u16, value: u8) -> Result<(), StepError> {
+        self.address_map
+            .write(addr, value)
+            .map_err(|reason| StepError::Write { addr, reason })?;
+        if self.result_trap == Some(addr) {
+            self.result_stored = Some(value);
+        }
+
+        Ok(())
+    }
+
+    /// Adds the elapsed cycles to the cycle count, invoking the cycle
+    /// callback once per cycle if one is set.
+    fn tick(&mut self, cycles: usize) {
+        for _ in 0..cycles {
+            self.cycles += 1;
+            if let Some(callback) = &self.cycle_callback {
+                (callback.inner)(self.cycles)
+            }
+        }
+    }
+
+    /// Fetches, decodes and generates the operation at the program counter in
+    /// two phases. The opcode is read first to determine the length of the
+    /// instruction, after which only the operand bytes the instruction
+    /// requires are read.
+    fn fetch_operations(&self) -> Result<operations::Operations, StepError> {
+        let pc = self.pc.read();
+        let escaped = !self.executable_regions.is_empty()
+            && !self.executable_regions.iter().any(|r| r.contains(&pc));
+        if let (true, Some(handler)) = (escaped, &self.escape_handler) {
+            (handler.inner)(pc)
+        }
+
+        let opcode = self.address_map.read(pc);
+        if let (true, Some(ops)) = (self.illegal_opcodes, operations::illegal_nop(self, opcode)) {
+            return Ok(ops);
+        }
+
+        // decode the opcode against zeroed operands to determine its length.
+        let byte_size = match operations::VariantParser.parse(&[opcode, 0, 0][..]) {
+            Ok(parcel::MatchStatus::Match { inner, .. }) => Ok(inner.offset()),
+            _ => Err(StepError::Decode { opcode, pc }),
+        }?;
+
+        let bytes: Vec<u8> = std::iter::once(opcode)
+            .chain(
+                (1..byte_size as u16).map(|offset| self.address_map.read(pc.wrapping_add(offset))),
+            )
+            .collect();
+
+        decode_operation(pc, &bytes).map(|oper| oper.generate(self))
+    }
+
+    /// Decodes and executes a single instruction from the provided bytes
+    /// rather than from the address map, returning the cycles it took. The
+    /// program counter is advanced by the length of the instruction. Failures
+    /// are returned as with `step`.
+    pub fn execute_bytes(&mut self, bytes: &[u8]) -> Result<usize, StepError> {
+        let mops = decode_operation(self.pc.read(), bytes)?.generate(self);
+        let cycles = mops.cycles();
+
+        let microcode_steps: Vec<Vec<microcode::Microcode>> = mops.into();
+        for cycle in microcode_steps.iter() {
+            self.execute_cycle(cycle)?;
+        }
+
+        Ok(cycles)
+    }
+
+    /// Fetches, decodes and executes the single instruction at the program
+    /// counter, returning the number of cycles it consumed. An error is
+    /// returned, leaving the cpu unmodified, if the opcode can't be decoded,
+    /// or if the bus rejects a write, abandoning the instruction at that
+    /// write.
+    pub fn step(&mut self) -> Result<usize, StepError> {
+        // complete an instruction left in flight by `run` before fetching the
+        // next.
+        if !self.in_flight.is_empty() {
+            return self.complete_in_flight();
+        }
+
+        if let Some(callback) = &self.trace_callback {
+            (callback.inner)(&self.trace_line());
+        }
+
+        let mops = self.fetch_operations()?;
+        let cycles = mops.cycles();
+
+        let microcode_steps: Vec<Vec<microcode::Microcode>> = mops.into();
+        for cycle in microcode_steps.iter() {
+            self.execute_cycle(cycle)?;
+        }
+        self.tick(cycles);
+
+        Ok(cycles)
+    }
+
+    /// Steps whole instructions until at least `max_cycles` cycles have
+    /// elapsed, returning the number of cycles executed. An instruction that
+    /// overshoots the budget is executed in full and included in the total.
+    /// Execution halts early on reaching a configured trap.
+    pub fn run_for(&mut self, max_cycles: usize) -> Result<usize, StepError> {
+        let mut elapsed = 0;
+        self.stop_reason = None;
+        self.result_stored = None;
+
+        while elapsed < max_cycles {
+            if let Some(reason) = self.pending_trap() {
+                self.stop_reason = Some(reason);
+                break;
+            }
+            elapsed += self.step()?;
+        }
+
+        Ok(elapsed)
+    }
+
+    /// Invokes the stack guard handler, if the guard is enabled, for a stack
+    /// pointer that has wrapped from `sp_before` to `sp_after`.
+    fn stack_wrapped(&self, sp_before: u8, sp_after: u8) {
+        if let (true, Some(guard)) = (self.stack_guard_enabled, &self.stack_guard) {
+            (guard.inner)(self.pc.read(), sp_before, sp_after)
+        }
+    }
+}
+
+/// Decodes a single instruction from the head of the provided bytes, read
+/// from the program counter.
+fn decode_operation(pc: u16, bytes: &[u8]) -> Result<InstructionVariant, StepError> {
+    match operations::VariantParser.parse(bytes) {
+        Ok(parcel::MatchStatus::Match { inner, .. }) => Ok(inner),
+        _ => Err(StepError::Decode {
+            opcode: bytes.first().copied().unwrap_or_default(),
+            pc,
+        }),
+    }
 }
 
 impl Default for Mos6502 {
     fn default() -> Self {
-        Self {
-            address_map: AddressMap::new()
+        Self::with_addressmap(
+            AddressMap::new()
                 .register(0x0000..=0x00FF, Box::new(StackMemory::new(0x00, 0xFF)))
                 .unwrap()
                 .register(0x0100..=0x01FF, Box::new(Ram::new(0x0100, 0x01FF)))
                 .unwrap(),
-            acc: GeneralPurpose::default(),
-            x: GeneralPurpose::default(),
-            y: GeneralPurpose::default(),
-            sp: StackPointer::default(),
-            pc: ProgramCounter::default(),
-            ps: ProcessorStatus::default(),
-        }
+        )
     }
 }
 
-impl Cpu<Mos6502> for Mos6502 {
-    fn run(self, cycles: usize) -> StepState<Mos6502> {
-        let state = self
-            .clone()
-            .into_iter()
-            .map(Into::<Vec<Vec<microcode::Microcode>>>::into)
-            .flatten() // flatten instructions to cycles
-            .take(cycles)
-            .flatten()
-            .fold(self, |c, mc| Execute::execute(mc, c));
-        StepState::from(state)
+impl<B: Addressable<u16, u8>> Cpu<Mos6502<B>> for Mos6502<B> {
+    /// Runs the cpu for the provided number of cycles, executing the
+    /// microcode scheduled for each cycle. An instruction that is still in
+    /// flight when the budget runs out is resumed by the following call
+    /// rather than being fetched again. An instruction that fails to decode
+    /// or execute halts the run with `StopReason::Fault`.
+    fn run(mut self, cycles: usize) -> StepState<Mos6502<B>> {
+        self.stop_reason = None;
+        self.result_stored = None;
+
+        for _ in 0..cycles {
+            if self.in_flight.is_empty() {
+                // Halt on a configured trap
+                if let Some(reason) = self.pending_trap() {
+                    self.stop_reason = Some(reason);
+                    break;
+                }
+
+                if let Some(callback) = &self.trace_callback {
+                    (callback.inner)(&self.trace_line());
+                }
+
+                match self.fetch_operations() {
+                    Ok(mops) => {
+                        self.in_flight = Into::<Vec<Vec<microcode::Microcode>>>::into(mops).into()
+                    }
+                    Err(e) => {
+                        self.stop_reason = Some(StopReason::Fault(e));
+                        break;
+                    }
+                }
+            }
+
+            if let Some(cycle) = self.in_flight.pop_front() {
+                if let Err(e) = self.execute_cycle(&cycle) {
+                    self.in_flight.clear();
+                    self.stop_reason = Some(StopReason::Fault(e));
+                    break;
+                }
+            }
+            self.tick(1);
+        }
+
+        if self.stop_reason.is_none() && self.in_flight.is_empty() {
+            self.stop_reason = self.pending_trap();
+        }
+        StepState::from(self)
     }
 }
 
-impl IntoIterator for Mos6502 {
+impl<B: Addressable<u16, u8>> IntoIterator for Mos6502<B> {
     type Item = operations::Operations;
-    type IntoIter = Mos6502IntoIterator;
+    type IntoIter = Mos6502IntoIterator<B>;
 
     fn into_iter(self) -> Self::IntoIter {
         Mos6502IntoIterator::new(self)
     }
 }
 
-pub struct Mos6502IntoIterator {
-    state: Mos6502,
+pub struct Mos6502IntoIterator<B = AddressMap<u16, u8>> {
+    state: Mos6502<B>,
 }
 
-impl From<Mos6502IntoIterator> for Mos6502 {
-    fn from(src: Mos6502IntoIterator) -> Self {
+impl<B> From<Mos6502IntoIterator<B>> for Mos6502<B> {
+    fn from(src: Mos6502IntoIterator<B>) -> Self {
         src.state
     }
 }
 
-impl Mos6502IntoIterator {
-    fn new(state: Mos6502) -> Self {
+impl<B> Mos6502IntoIterator<B> {
+    fn new(state: Mos6502<B>) -> Self {
         Mos6502IntoIterator { state }
     }
 }
 
-impl Iterator for Mos6502IntoIterator {
+impl<B: Addressable<u16, u8>> Iterator for Mos6502IntoIterator<B> {
     type Item = operations::Operations;
 
     fn next(&mut self) -> Option<operations::Operations> {
-        let pc = self.state.pc.read();
-        let opcodes: [u8; 3] = [
-            self.state.address_map.read(pc),
-            self.state.address_map.read(pc + 1),
-            self.state.address_map.read(pc + 2),
-        ];
-
-        // Parse correct operation
-        let oper = match operations::VariantParser.parse(&opcodes[..]) {
-            Ok(parcel::MatchStatus::Match {
-                span: _,
-                remainder: _,
-                inner: op,
-            }) => Ok(op),
-            _ => Err(format!("No match found for {}", opcodes[0])),
+        // Halt on a configured trap
+        if self.state.pending_trap().is_some() {
+            return None;
         }
-        .unwrap();
 
-        let mops = oper.generate(&self.state);
+        // Parse correct operation, halting on a fault
+        let mops = match self.state.fetch_operations() {
+            Ok(mops) => mops,
+            Err(e) => {
+                self.state.stop_reason = Some(StopReason::Fault(e));
+                return None;
+            }
+        };
 
         let microcode_steps: Vec<Vec<microcode::Microcode>> = mops.clone().into();
-        for mc in microcode_steps.iter().flatten() {
-            self.state.execute_mut(mc)
+        for cycle in microcode_steps.iter() {
+            if let Err(e) = self.state.execute_cycle(cycle) {
+                self.state.stop_reason = Some(StopReason::Fault(e));
+                return None;
+            }
         }
 
         Some(mops)
@@ -262,20 +814,21 @@ impl Iterator for Mos6502IntoIterator {
 // microcode execution
 
 // For any implementation of ExecuteMut<M> for a given CPU Execute is implemented.
-impl<M> crate::cpu::Execute<Mos6502> for M
+impl<M, B> crate::cpu::Execute<Mos6502<B>> for M
 where
-    Mos6502: ExecuteMut<M>,
+    Mos6502<B>: ExecuteMut<M>,
 {
-    fn execute(self, mut cpu: Mos6502) -> Mos6502 {
+    fn execute(self, mut cpu: Mos6502<B>) -> Mos6502<B> {
         cpu.execute_mut(&self);
         cpu
     }
 }
 
-impl ExecuteMut<microcode::Microcode> for Mos6502 {
+impl<B: Addressable<u16, u8>> ExecuteMut<microcode::Microcode> for Mos6502<B> {
     fn execute_mut(&mut self, mc: &microcode::Microcode) {
         match mc {
             microcode::Microcode::WriteMemory(mc) => self.execute_mut(mc),
+            microcode::Microcode::DummyWriteMemory(mc) => self.execute_mut(mc),
             microcode::Microcode::SetProgramStatusFlagState(mc) => self.execute_mut(mc),
             microcode::Microcode::Write8bitRegister(mc) => self.execute_mut(mc),
             microcode::Microcode::Inc8bitRegister(mc) => self.execute_mut(mc),
@@ -287,13 +840,19 @@ impl ExecuteMut<microcode::Microcode> for Mos6502 {
     }
 }
 
-impl ExecuteMut<microcode::WriteMemory> for Mos6502 {
+impl<B: Addressable<u16, u8>> ExecuteMut<microcode::WriteMemory> for Mos6502<B> {
     fn execute_mut(&mut self, mc: &microcode::WriteMemory) {
+        self.write_memory(mc.address, mc.value).unwrap();
+    }
+}
+
+impl<B: Addressable<u16, u8>> ExecuteMut<microcode::DummyWriteMemory> for Mos6502<B> {
+    fn execute_mut(&mut self, mc: &microcode::DummyWriteMemory) {
         self.address_map.write(mc.address, mc.value).unwrap();
     }
 }
 
-impl ExecuteMut<microcode::SetProgramStatusFlagState> for Mos6502 {
+impl<B: Addressable<u16, u8>> ExecuteMut<microcode::SetProgramStatusFlagState> for Mos6502<B> {
     fn execute_mut(&mut self, mc: &microcode::SetProgramStatusFlagState) {
         let mut status = self.ps;
 
@@ -311,7 +870,7 @@ impl ExecuteMut<microcode::SetProgramStatusFlagState> for Mos6502 {
     }
 }
 
-impl ExecuteMut<microcode::Write8bitRegister> for Mos6502 {
+impl<B: Addressable<u16, u8>> ExecuteMut<microcode::Write8bitRegister> for Mos6502<B> {
     fn execute_mut(&mut self, mc: &microcode::Write8bitRegister) {
         let register = mc.register;
         let value = mc.value;
@@ -332,7 +891,7 @@ impl ExecuteMut<microcode::Write8bitRegister> for Mos6502 {
     }
 }
 
-impl ExecuteMut<microcode::Inc8bitRegister> for Mos6502 {
+impl<B: Addressable<u16, u8>> ExecuteMut<microcode::Inc8bitRegister> for Mos6502<B> {
     fn execute_mut(&mut self, mc: &microcode::Inc8bitRegister) {
         let register = mc.register;
         let value = mc.value;
@@ -352,7 +911,11 @@ impl ExecuteMut<microcode::Inc8bitRegister> for Mos6502 {
             }
             ByteRegisters::Sp => {
                 let old_val = self.sp.read();
-                self.sp = StackPointer::with_value(old_val.overflowing_add(value).0);
+                let (new_val, wrapped) = old_val.overflowing_add(value);
+                self.sp = StackPointer::with_value(new_val);
+                if wrapped {
+                    self.stack_wrapped(old_val, new_val);
+                }
             }
             ByteRegisters::Ps => {
                 let old_val = self.ps.read();
@@ -362,7 +925,7 @@ impl ExecuteMut<microcode::Inc8bitRegister> for Mos6502 {
     }
 }
 
-impl ExecuteMut<microcode::Dec8bitRegister> for Mos6502 {
+impl<B: Addressable<u16, u8>> ExecuteMut<microcode::Dec8bitRegister> for Mos6502<B> {
     fn execute_mut(&mut self, mc: &microcode::Dec8bitRegister) {
         let register = mc.register;
         let value = mc.value;
@@ -382,7 +945,11 @@ impl ExecuteMut<microcode::Dec8bitRegister> for Mos6502 {
             }
             ByteRegisters::Sp => {
                 let old_val = self.sp.read();
-                self.sp = StackPointer::with_value(old_val.overflowing_sub(value).0);
+                let (new_val, wrapped) = old_val.overflowing_sub(value);
+                self.sp = StackPointer::with_value(new_val);
+                if wrapped {
+                    self.stack_wrapped(old_val, new_val);
+                }
             }
             ByteRegisters::Ps => {
                 let old_val = self.ps.read();
@@ -392,20 +959,20 @@ impl ExecuteMut<microcode::Dec8bitRegister> for Mos6502 {
     }
 }
 
-impl ExecuteMut<microcode::Write16bitRegister> for Mos6502 {
+impl<B: Addressable<u16, u8>> ExecuteMut<microcode::Write16bitRegister> for Mos6502<B> {
     fn execute_mut(&mut self, mc: &microcode::Write16bitRegister) {
         self.pc = ProgramCounter::with_value(mc.value);
     }
 }
 
-impl ExecuteMut<microcode::Inc16bitRegister> for Mos6502 {
+impl<B: Addressable<u16, u8>> ExecuteMut<microcode::Inc16bitRegister> for Mos6502<B> {
     fn execute_mut(&mut self, mc: &microcode::Inc16bitRegister) {
         let pc = self.pc.read().overflowing_add(mc.value).0;
         self.pc = ProgramCounter::with_value(pc);
     }
 }
 
-impl ExecuteMut<microcode::Dec16bitRegister> for Mos6502 {
+impl<B: Addressable<u16, u8>> ExecuteMut<microcode::Dec16bitRegister> for Mos6502<B> {
     fn execute_mut(&mut self, mc: &microcode::Dec16bitRegister) {
         let pc = self.pc.read().overflowing_sub(mc.value).0;
         self.pc = ProgramCounter::with_value(pc);
@@ -0,0 +1,97 @@
+use super::{Operations, VariantParser};
+use crate::address_map::{AddressMap, Addressable};
+use crate::cpu::{
+    mos6502::{
+        microcode::Microcode,
+        register::{ByteRegisters, ProgramStatusFlags, WordRegisters},
+        Mos6502,
+    },
+    Generate,
+};
+use parcel::Parser;
+use std::{cell::Cell, rc::Rc};
+
+/// InstructionEffects summarizes the side-effects of an instruction, as
+/// derived from the microcode it generates. The implicit advance of the
+/// program counter past the instruction is not included.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct InstructionEffects {
+    pub byte_registers: Vec<ByteRegisters>,
+    pub word_registers: Vec<WordRegisters>,
+    pub flags: Vec<ProgramStatusFlags>,
+    pub reads_memory: bool,
+    pub writes_memory: bool,
+}
+
+/// Records whether any address has been read from while generating an
+/// instruction's microcode.
+#[derive(Clone)]
+struct ReadRecorder {
+    read: Rc<Cell<bool>>,
+}
+
+impl Addressable<u16, u8> for ReadRecorder {
+    fn read(&self, _: u16) -> u8 {
+        self.read.set(true);
+        0
+    }
+
+    fn write(&mut self, _: u16, value: u8) -> Result<u8, String> {
+        Ok(value)
+    }
+}
+
+/// Returns a summary of the registers, flags and memory affected by an
+/// opcode, introspected from the microcode generated against a zeroed cpu.
+/// Returns `None` if the opcode is not implemented.
+pub fn instruction_effects(opcode: u8) -> Option<InstructionEffects> {
+    let inst = match VariantParser.parse(&[opcode, 0, 0][..]) {
+        Ok(parcel::MatchStatus::Match { inner, .. }) => Some(inner),
+        _ => None,
+    }?;
+
+    let read = Rc::new(Cell::new(false));
+    let am = AddressMap::new()
+        .register(
+            0x0000..=0xffff,
+            Box::new(ReadRecorder { read: read.clone() }),
+        )
+        .unwrap();
+    let cpu = Mos6502::with_addressmap(am);
+    let ops: Operations = inst.generate(&cpu);
+
+    let mut effects = InstructionEffects {
+        reads_memory: read.get(),
+        ..InstructionEffects::default()
+    };
+    for mc in ops.microcode.iter() {
+        match mc {
+            Microcode::WriteMemory(_) | Microcode::DummyWriteMemory(_) => {
+                effects.writes_memory = true
+            }
+            Microcode::SetProgramStatusFlagState(mc) => push_unique(&mut effects.flags, mc.flag),
+            Microcode::Write8bitRegister(mc) => {
+                push_unique(&mut effects.byte_registers, mc.register)
+            }
+            Microcode::Inc8bitRegister(mc) => push_unique(&mut effects.byte_registers, mc.register),
+            Microcode::Dec8bitRegister(mc) => push_unique(&mut effects.byte_registers, mc.register),
+            Microcode::Write16bitRegister(mc) => {
+                push_unique(&mut effects.word_registers, mc.register)
+            }
+            Microcode::Inc16bitRegister(mc) => {
+                push_unique(&mut effects.word_registers, mc.register)
+            }
+            Microcode::Dec16bitRegister(mc) => {
+                push_unique(&mut effects.word_registers, mc.register)
+            }
+        }
+    }
+
+    Some(effects)
+}
+
+fn push_unique<T: PartialEq>(items: &mut Vec<T>, item: T) {
+    if !items.contains(&item) {
+        items.push(item)
+    }
+}
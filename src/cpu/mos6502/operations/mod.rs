@@ -1,6 +1,6 @@
 use crate::address_map::{page::Page, Addressable};
 use crate::cpu::{
-    mos6502::{microcode::Microcode, register::*, Generate, Mos6502, IRQ_VECTOR_HH, IRQ_VECTOR_LL},
+    mos6502::{microcode::Microcode, register::*, Generate, Mos6502, Variant, IRQ_VECTOR_LL},
     register::Register,
     Cyclable, Offset,
 };
@@ -9,6 +9,9 @@ use parcel::{ParseResult, Parser};
 use std::fmt::Debug;
 use std::num::Wrapping;
 
+mod effects;
+pub use effects::{instruction_effects, InstructionEffects};
+
 #[cfg(test)]
 mod tests;
 
@@ -96,6 +99,8 @@ where
 }
 
 impl Operand<u8> {
+    /// Constructs an Operand from a value, computing the negative and zero
+    /// flags from the value itself. Carry is always unset.
     pub fn new(inner: u8) -> Self {
         Self {
             carry: false,
@@ -104,6 +109,13 @@ impl Operand<u8> {
             inner,
         }
     }
+
+    /// Constructs an Operand from a value with all flags unset. This should
+    /// be used by instructions that derive their flags from a source other
+    /// than the value, such as BIT.
+    pub fn with_value_no_flags(inner: u8) -> Self {
+        Self::with_flags(inner, false, false, false)
+    }
 }
 
 impl std::ops::Add for Operand<u8> {
@@ -150,9 +162,22 @@ impl SubTwosComplement for Operand<u8> {
     type Output = Self;
 
     fn twos_complement_sub(self, other: Self, carry: bool) -> (Self::Output, bool) {
-        let carry_bit = carry as u8; // 1 if true 0 if false
-        let rhs_ones_complement = Operand::new(255 - other.unwrap()) + Operand::new(carry_bit);
-        self.twos_complement_add(rhs_ones_complement, carry)
+        let (lhs, rhs) = (self.unwrap(), other.unwrap());
+        // subtraction is the addition of the ones complement and the carry,
+        // with carry out signifying that no borrow occurred.
+        let sum = lhs as u16 + !rhs as u16 + carry as u16;
+        let difference = Operand::new(sum as u8);
+        let overflow = (lhs ^ difference.unwrap()) & (lhs ^ rhs) & 0x80 != 0;
+
+        (
+            Operand::with_flags(
+                difference.unwrap(),
+                sum > 0xff,
+                difference.negative,
+                difference.zero,
+            ),
+            overflow,
+        )
     }
 }
 
@@ -255,7 +280,11 @@ fn add_index_to_zeropage_address(zeropage_addr: u8, index: u8) -> u16 {
 /// Provides a wrapper around the common operation of dereferencing an indexed
 /// indirect address. This is effectively taking the value at
 /// (Operand + Index, addr at Operand + Index + 1).
-fn dereference_indexed_indirect_address(cpu: &Mos6502, base_addr: u8, index: u8) -> u16 {
+fn dereference_indexed_indirect_address<B: Addressable<u16, u8>>(
+    cpu: &Mos6502<B>,
+    base_addr: u8,
+    index: u8,
+) -> u16 {
     u16::from_le_bytes([
         cpu.address_map
             .read(base_addr.overflowing_add(index).0 as u16),
@@ -267,23 +296,168 @@ fn dereference_indexed_indirect_address(cpu: &Mos6502, base_addr: u8, index: u8)
 /// Provides a wrapper around the operation of dereferencing an indirect
 /// address and then adding an index to that indirect address. This is
 /// effectively the value at (Operand, Operand + 1) + Index.
-fn dereference_indirect_indexed_address(cpu: &Mos6502, base_addr: u8, index: u8) -> u16 {
+fn dereference_indirect_indexed_address<B: Addressable<u16, u8>>(
+    cpu: &Mos6502<B>,
+    base_addr: u8,
+    index: u8,
+) -> u16 {
     u16::from_le_bytes([
         cpu.address_map.read(base_addr as u16),
         cpu.address_map.read(base_addr.overflowing_add(1).0 as u16),
     ]) + index as u16
 }
 
+/// Returns the 1 cycle penalty incurred when an indexed address falls outside
+/// the page of its base address. Cpus in fast mode never incur this penalty.
+fn page_cross_penalty<B: Addressable<u16, u8>>(
+    cpu: &Mos6502<B>,
+    base_addr: u16,
+    indexed_addr: u16,
+) -> usize {
+    if cpu.fast_mode || Page::from(base_addr).contains(indexed_addr) {
+        0
+    } else {
+        1
+    }
+}
+
+/// Returns the write of the unmodified value that read-modify-write
+/// instructions perform before writing their result, when the cpu is
+/// cycle-accurate.
+fn rmw_dummy_write<B: Addressable<u16, u8>>(
+    cpu: &Mos6502<B>,
+    addr: u16,
+    original: u8,
+) -> Option<Microcode> {
+    if cpu.cycle_accurate {
+        Some(gen_dummy_write_memory_microcode!(addr, original))
+    } else {
+        None
+    }
+}
+
+/// Constructs the operations of a read-modify-write instruction targeting
+/// `addr`, with its microcode executing on the final cycle preceded, when the
+/// cpu is cycle-accurate, by a write of the unmodified value.
+fn rmw_operations<B: Addressable<u16, u8>>(
+    cpu: &Mos6502<B>,
+    offset: usize,
+    cycles: usize,
+    addr: u16,
+    microcode: Vec<Microcode>,
+) -> Operations {
+    let last = cycles - 1;
+    let original = cpu.address_map.read(addr);
+
+    match rmw_dummy_write(cpu, addr, original) {
+        Some(dummy_write) => Operations::scheduled(
+            offset,
+            cycles,
+            std::iter::once((last - 1, dummy_write))
+                .chain(microcode.into_iter().map(|mc| (last, mc)))
+                .collect(),
+        ),
+        None => Operations::new(offset, cycles, microcode),
+    }
+}
+
 /// Provides a wrapper around the common operation of dereferencing and address
 /// mode and retrieving the value stored at the specified address from the
 /// address map. This value is then returned in a wrapper Operand.
-fn dereference_address_to_operand(cpu: &Mos6502, addr: u16, index: u8) -> Operand<u8> {
+fn dereference_address_to_operand<B: Addressable<u16, u8>>(
+    cpu: &Mos6502<B>,
+    addr: u16,
+    index: u8,
+) -> Operand<u8> {
     Operand::new(
         cpu.address_map
             .read(add_index_to_address(addr as u16, index)),
     )
 }
 
+/// Adds the operand to the accumulator with the carry in, returning the result
+/// and the overflow flag. When the decimal flag is set the operands are
+/// treated as packed binary-coded decimal.
+fn add_with_carry<B: Addressable<u16, u8>>(
+    cpu: &Mos6502<B>,
+    rhs: Operand<u8>,
+) -> (Operand<u8>, bool) {
+    let lhs = Operand::new(cpu.acc.read());
+    let (value, overflow) = lhs.twos_complement_add(rhs, cpu.ps.carry);
+
+    if cpu.ps.decimal {
+        (
+            decimal_add(lhs.unwrap(), rhs.unwrap(), cpu.ps.carry),
+            overflow,
+        )
+    } else {
+        (value, overflow)
+    }
+}
+
+/// Adds two packed binary-coded decimal values, correcting each nibble that
+/// exceeds 9. Carry is set when the decimal sum exceeds 99.
+fn decimal_add(lhs: u8, rhs: u8, carry: bool) -> Operand<u8> {
+    let mut lo = (lhs & 0x0f) + (rhs & 0x0f) + carry as u8;
+    if lo > 0x09 {
+        lo += 0x06;
+    }
+
+    let mut hi = (lhs >> 4) + (rhs >> 4) + (lo > 0x0f) as u8;
+    if hi > 0x09 {
+        hi += 0x06;
+    }
+
+    let value = Operand::new(((hi & 0x0f) << 4) | (lo & 0x0f));
+    Operand::with_flags(value.unwrap(), hi > 0x0f, value.negative, value.zero)
+}
+
+/// Subtracts the operand from the accumulator, borrowing when carry is unset,
+/// returning the result and the overflow flag. When the decimal flag is set
+/// the operands are treated as packed binary-coded decimal.
+fn subtract_with_borrow<B: Addressable<u16, u8>>(
+    cpu: &Mos6502<B>,
+    rhs: Operand<u8>,
+) -> (Operand<u8>, bool) {
+    let lhs = Operand::new(cpu.acc.read());
+    let (value, overflow) = lhs.twos_complement_sub(rhs, cpu.ps.carry);
+
+    if cpu.ps.decimal {
+        (
+            decimal_sub(lhs.unwrap(), rhs.unwrap(), cpu.ps.carry),
+            overflow,
+        )
+    } else {
+        (value, overflow)
+    }
+}
+
+/// Subtracts two packed binary-coded decimal values, correcting each nibble
+/// that borrows. Carry is cleared when the result underflows.
+fn decimal_sub(lhs: u8, rhs: u8, carry: bool) -> Operand<u8> {
+    let mut lo = (lhs & 0x0f) as i16 - (rhs & 0x0f) as i16 - (!carry) as i16;
+    let lo_borrow = lo < 0;
+    if lo_borrow {
+        lo -= 0x06;
+    }
+
+    let mut hi = (lhs >> 4) as i16 - (rhs >> 4) as i16 - lo_borrow as i16;
+    let hi_borrow = hi < 0;
+    if hi_borrow {
+        hi -= 0x06;
+    }
+
+    let value = Operand::new((((hi & 0x0f) << 4) | (lo & 0x0f)) as u8);
+    Operand::with_flags(value.unwrap(), !hi_borrow, value.negative, value.zero)
+}
+
+/// Converts a status byte pulled from the stack into the value loaded into the
+/// status register. The break flag only exists on the stack and is discarded,
+/// while the unused bit always reads as set.
+fn status_from_stack(value: u8) -> u8 {
+    (value & !0x10) | 0x20
+}
+
 /// Provides a wrapper around generating a 16-bit address from the stack
 /// pointer. This exists as a function solely to not lose intent in the type
 /// conversion to u16.
@@ -299,14 +473,33 @@ pub struct Operations {
     offset: usize,
     cycles: usize,
     microcode: Vec<Microcode>,
+    schedule: Option<Vec<usize>>,
 }
 
 impl Operations {
+    /// Constructs operations whose microcode all executes on the final cycle
+    /// of the instruction.
     pub fn new(offset: usize, cycles: usize, microcode: Vec<Microcode>) -> Self {
         Self {
             offset,
             cycles,
             microcode,
+            schedule: None,
+        }
+    }
+
+    /// Constructs operations whose microcode executes across the cycles of
+    /// the instruction, pairing each operation with the zero-indexed cycle it
+    /// executes on. Cycles past the end of the instruction are clamped to its
+    /// final cycle.
+    pub fn scheduled(offset: usize, cycles: usize, microcode: Vec<(usize, Microcode)>) -> Self {
+        let (schedule, microcode) = microcode.into_iter().unzip();
+
+        Self {
+            offset,
+            cycles,
+            microcode,
+            schedule: Some(schedule),
         }
     }
 }
@@ -325,25 +518,27 @@ impl Offset for Operations {
 
 impl From<Operations> for Vec<Vec<Microcode>> {
     fn from(src: Operations) -> Self {
-        let cycles = src.cycles();
+        // an operation always takes at least one cycle, guarding against
+        // underflow on malformed operations.
+        let cycles = src.cycles().max(1);
+        let last = cycles - 1;
         let offset = src.offset() as u16;
-        let mut mcs = vec![Vec::<Microcode>::new(); cycles - 1];
-
-        mcs.push(
-            src.microcode
-                .into_iter()
-                .chain(
-                    vec![gen_inc_16bit_register_microcode!(WordRegisters::Pc, offset)].into_iter(),
-                )
-                .collect(),
-        );
+        let mut mcs = vec![Vec::<Microcode>::new(); cycles];
+
+        let len = src.microcode.len();
+        let schedule = src.schedule.unwrap_or_else(|| vec![last; len]);
+        for (cycle, mc) in schedule.into_iter().zip(src.microcode.into_iter()) {
+            mcs[cycle.min(last)].push(mc);
+        }
+
+        mcs[last].push(gen_inc_16bit_register_microcode!(WordRegisters::Pc, offset));
         mcs
     }
 }
 
 /// Dispatch a generate method to each corresponding generic types generate method.
-impl Generate<Mos6502, Operations> for InstructionVariant {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations> for InstructionVariant {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         match *self {
             InstructionVariant::AdcAbsolute(am) => {
                 Instruction::new(mnemonic::Adc, addressing_mode::Absolute(am)).generate(cpu)
@@ -1211,13 +1406,13 @@ where
 
 // Adc
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Adc, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
-        let lhs = Operand::new(cpu.acc.read());
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Adc, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap(), 0);
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_add(rhs, cpu.ps.carry);
+        let (value, overflow) = add_with_carry(cpu, rhs);
 
         Operations::new(
             self.offset(),
@@ -1233,24 +1428,18 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Adc, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Adc, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, cpu.x.read());
-        let lhs = Operand::new(cpu.acc.read());
         let rhs = dereference_address_to_operand(cpu, indexed_addr, 0);
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_add(rhs, cpu.ps.carry);
+        let (value, overflow) = add_with_carry(cpu, rhs);
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -1266,24 +1455,18 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Adc, addressing_mode::AbsoluteIndexedWithY>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, cpu.y.read());
-        let lhs = Operand::new(cpu.acc.read());
         let rhs = dereference_address_to_operand(cpu, indexed_addr, 0);
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_add(rhs, cpu.ps.carry);
+        let (value, overflow) = add_with_carry(cpu, rhs);
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -1299,25 +1482,19 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Adc, addressing_mode::IndirectYIndexed>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let zpage_base_addr = self.addressing_mode.unwrap();
         let indirect_addr =
             dereference_indirect_indexed_address(cpu, zpage_base_addr, cpu.y.read());
-        let lhs = Operand::new(cpu.acc.read());
         let rhs = Operand::new(cpu.address_map.read(indirect_addr));
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_add(rhs, cpu.ps.carry);
+        let (value, overflow) = add_with_carry(cpu, rhs);
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(zpage_base_addr as u16).contains(indirect_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, zpage_base_addr as u16, indirect_addr);
 
         Operations::new(
             self.offset(),
@@ -1333,13 +1510,13 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Adc, addressing_mode::Immediate> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
-        let lhs = Operand::new(cpu.acc.read());
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Adc, addressing_mode::Immediate>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let rhs = Operand::new(self.addressing_mode.unwrap());
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_add(rhs, cpu.ps.carry);
+        let (value, overflow) = add_with_carry(cpu, rhs);
 
         Operations::new(
             self.offset(),
@@ -1355,17 +1532,15 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Adc, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Adc, addressing_mode::XIndexedIndirect>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let indirect_addr =
             dereference_indexed_indirect_address(cpu, self.addressing_mode.unwrap(), cpu.x.read());
-        let lhs = Operand::new(cpu.acc.read());
         let rhs = Operand::new(cpu.address_map.read(indirect_addr));
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_add(rhs, cpu.ps.carry);
+        let (value, overflow) = add_with_carry(cpu, rhs);
 
         Operations::new(
             self.offset(),
@@ -1381,14 +1556,14 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Adc, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Adc, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), 0);
-        let lhs = Operand::new(cpu.acc.read());
         let rhs = dereference_address_to_operand(cpu, addr, 0);
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_add(rhs, cpu.ps.carry);
+        let (value, overflow) = add_with_carry(cpu, rhs);
 
         Operations::new(
             self.offset(),
@@ -1404,17 +1579,15 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Adc, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Adc, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_zeropage_address(addr, cpu.x.read());
-        let lhs = Operand::new(cpu.acc.read());
         let rhs = dereference_address_to_operand(cpu, indexed_addr, 0);
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_add(rhs, cpu.ps.carry);
+        let (value, overflow) = add_with_carry(cpu, rhs);
 
         Operations::new(
             self.offset(),
@@ -1432,13 +1605,13 @@ impl Generate<Mos6502, Operations>
 
 // Sbc
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sbc, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
-        let lhs = Operand::new(cpu.acc.read());
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Sbc, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap(), 0);
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_sub(rhs, cpu.ps.carry);
+        let (value, overflow) = subtract_with_borrow(cpu, rhs);
 
         Operations::new(
             self.offset(),
@@ -1454,24 +1627,18 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sbc, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Sbc, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, cpu.x.read());
-        let lhs = Operand::new(cpu.acc.read());
         let rhs = dereference_address_to_operand(cpu, indexed_addr, 0);
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_sub(rhs, cpu.ps.carry);
+        let (value, overflow) = subtract_with_borrow(cpu, rhs);
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -1487,24 +1654,18 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Sbc, addressing_mode::AbsoluteIndexedWithY>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, cpu.y.read());
-        let lhs = Operand::new(cpu.acc.read());
         let rhs = dereference_address_to_operand(cpu, indexed_addr, 0);
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_sub(rhs, cpu.ps.carry);
+        let (value, overflow) = subtract_with_borrow(cpu, rhs);
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -1520,25 +1681,19 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Sbc, addressing_mode::IndirectYIndexed>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let zpage_base_addr = self.addressing_mode.unwrap();
         let indirect_addr =
             dereference_indirect_indexed_address(cpu, zpage_base_addr, cpu.y.read());
-        let lhs = Operand::new(cpu.acc.read());
         let rhs = Operand::new(cpu.address_map.read(indirect_addr));
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_sub(rhs, cpu.ps.carry);
+        let (value, overflow) = subtract_with_borrow(cpu, rhs);
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(zpage_base_addr as u16).contains(indirect_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, zpage_base_addr as u16, indirect_addr);
 
         Operations::new(
             self.offset(),
@@ -1554,13 +1709,13 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sbc, addressing_mode::Immediate> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
-        let lhs = Operand::new(cpu.acc.read());
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Sbc, addressing_mode::Immediate>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let rhs = Operand::new(self.addressing_mode.unwrap());
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_sub(rhs, cpu.ps.carry);
+        let (value, overflow) = subtract_with_borrow(cpu, rhs);
 
         Operations::new(
             self.offset(),
@@ -1576,17 +1731,15 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sbc, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Sbc, addressing_mode::XIndexedIndirect>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let indirect_addr =
             dereference_indexed_indirect_address(cpu, self.addressing_mode.unwrap(), cpu.x.read());
-        let lhs = Operand::new(cpu.acc.read());
         let rhs = Operand::new(cpu.address_map.read(indirect_addr));
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_sub(rhs, cpu.ps.carry);
+        let (value, overflow) = subtract_with_borrow(cpu, rhs);
 
         Operations::new(
             self.offset(),
@@ -1602,14 +1755,14 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sbc, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Sbc, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), 0);
-        let lhs = Operand::new(cpu.acc.read());
         let rhs = dereference_address_to_operand(cpu, addr, 0);
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_sub(rhs, cpu.ps.carry);
+        let (value, overflow) = subtract_with_borrow(cpu, rhs);
 
         Operations::new(
             self.offset(),
@@ -1625,17 +1778,15 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sbc, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Sbc, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_zeropage_address(addr, cpu.x.read());
-        let lhs = Operand::new(cpu.acc.read());
         let rhs = dereference_address_to_operand(cpu, indexed_addr, 0);
 
-        // calculate overflow
-        let (value, overflow) = lhs.twos_complement_sub(rhs, cpu.ps.carry);
+        let (value, overflow) = subtract_with_borrow(cpu, rhs);
 
         Operations::new(
             self.offset(),
@@ -1655,8 +1806,10 @@ impl Generate<Mos6502, Operations>
 
 // And
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::And, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::And, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let lhs = Operand::new(cpu.acc.read());
         let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap(), 0);
         let value = lhs & rhs;
@@ -1673,10 +1826,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::And, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::And, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
@@ -1685,11 +1838,7 @@ impl Generate<Mos6502, Operations>
         let value = lhs & rhs;
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -1703,10 +1852,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::And, addressing_mode::AbsoluteIndexedWithY>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.y.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
@@ -1715,11 +1864,7 @@ impl Generate<Mos6502, Operations>
         let value = lhs & rhs;
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -1733,10 +1878,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::And, addressing_mode::IndirectYIndexed>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let zpage_base_addr = self.addressing_mode.unwrap();
         let indirect_addr =
             dereference_indirect_indexed_address(cpu, zpage_base_addr, cpu.y.read());
@@ -1745,11 +1890,7 @@ impl Generate<Mos6502, Operations>
         let value = lhs & rhs;
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(zpage_base_addr as u16).contains(indirect_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, zpage_base_addr as u16, indirect_addr);
 
         Operations::new(
             self.offset(),
@@ -1763,8 +1904,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::And, addressing_mode::Immediate> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::And, addressing_mode::Immediate>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let lhs = Operand::new(cpu.acc.read());
         let rhs = Operand::new(self.addressing_mode.unwrap());
         let value = lhs & rhs;
@@ -1781,10 +1924,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::And, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::And, addressing_mode::XIndexedIndirect>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let indirect_addr =
             dereference_indexed_indirect_address(cpu, self.addressing_mode.unwrap(), cpu.x.read());
         let lhs = Operand::new(cpu.acc.read());
@@ -1803,8 +1946,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::And, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::And, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let lhs = Operand::new(cpu.acc.read());
         let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap() as u16, 0);
         let value = lhs & rhs;
@@ -1821,10 +1966,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::And, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::And, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let indexed_addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), index);
         let lhs = Operand::new(cpu.acc.read());
@@ -1845,14 +1990,18 @@ impl Generate<Mos6502, Operations>
 
 // Asl
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Asl, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Asl, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let value = dereference_address_to_operand(cpu, addr, 0) << Operand::new(1u8);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -1863,18 +2012,20 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Asl, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Asl, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
         let value = dereference_address_to_operand(cpu, addr, index) << Operand::new(1u8);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            indexed_addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -1885,8 +2036,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Asl, addressing_mode::Accumulator> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Asl, addressing_mode::Accumulator>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = Operand::new(cpu.acc.read()) << Operand::new(1u8);
 
         Operations::new(
@@ -1902,14 +2055,18 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Asl, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Asl, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Asl, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap() as u16;
         let value = dereference_address_to_operand(cpu, addr, 0) << Operand::new(1u8);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -1920,17 +2077,19 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Asl, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Asl, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let indexed_addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), index);
         let value = dereference_address_to_operand(cpu, indexed_addr, 0) << Operand::new(1u8);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            indexed_addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -1943,10 +2102,13 @@ impl Generate<Mos6502, Operations>
 
 // Bit
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bit, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
-        let lhs = Operand::new(cpu.acc.read());
-        let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap(), 0);
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Bit, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
+        let lhs = Operand::with_value_no_flags(cpu.acc.read());
+        let rhs = Operand::with_value_no_flags(cpu.address_map.read(self.addressing_mode.unwrap()));
+        // negative and overflow are taken from memory, zero from the result.
         let negative = bit_is_set(rhs.unwrap(), 7);
         let overflow = bit_is_set(rhs.unwrap(), 6);
         let value = lhs & rhs;
@@ -1963,11 +2125,14 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bit, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bit, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Bit, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), 0);
-        let lhs = Operand::new(cpu.acc.read());
-        let rhs = dereference_address_to_operand(cpu, addr, 0);
+        let lhs = Operand::with_value_no_flags(cpu.acc.read());
+        let rhs = Operand::with_value_no_flags(cpu.address_map.read(addr));
+        // negative and overflow are taken from memory, zero from the result.
         let negative = bit_is_set(rhs.unwrap(), 7);
         let overflow = bit_is_set(rhs.unwrap(), 6);
         let value = lhs & rhs;
@@ -1986,8 +2151,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bit, addressing_mod
 
 // Eor
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Eor, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Eor, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let lhs = Operand::new(cpu.acc.read());
         let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap(), 0);
         let value = lhs ^ rhs;
@@ -2004,10 +2171,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Eor, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Eor, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
@@ -2016,11 +2183,7 @@ impl Generate<Mos6502, Operations>
         let value = lhs ^ rhs;
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -2034,10 +2197,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Eor, addressing_mode::AbsoluteIndexedWithY>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.y.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
@@ -2046,11 +2209,7 @@ impl Generate<Mos6502, Operations>
         let value = lhs ^ rhs;
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -2064,10 +2223,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Eor, addressing_mode::IndirectYIndexed>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let zpage_base_addr = self.addressing_mode.unwrap();
         let indirect_addr =
             dereference_indirect_indexed_address(cpu, zpage_base_addr, cpu.y.read());
@@ -2076,11 +2235,7 @@ impl Generate<Mos6502, Operations>
         let value = lhs ^ rhs;
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(zpage_base_addr as u16).contains(indirect_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, zpage_base_addr as u16, indirect_addr);
 
         Operations::new(
             self.offset(),
@@ -2094,8 +2249,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Eor, addressing_mode::Immediate> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Eor, addressing_mode::Immediate>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let lhs = Operand::new(cpu.acc.read());
         let rhs = Operand::new(self.addressing_mode.unwrap());
         let value = lhs ^ rhs;
@@ -2112,10 +2269,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Eor, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Eor, addressing_mode::XIndexedIndirect>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let indirect_addr =
             dereference_indexed_indirect_address(cpu, self.addressing_mode.unwrap(), cpu.x.read());
         let lhs = Operand::new(cpu.acc.read());
@@ -2134,8 +2291,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Eor, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Eor, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let lhs = Operand::new(cpu.acc.read());
         let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap() as u16, 0);
         let value = lhs ^ rhs;
@@ -2152,10 +2311,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Eor, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Eor, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let indexed_addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), index);
         let lhs = Operand::new(cpu.acc.read());
@@ -2176,14 +2335,18 @@ impl Generate<Mos6502, Operations>
 
 // Lsr
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Lsr, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Lsr, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let value = dereference_address_to_operand(cpu, addr, 0) >> Operand::new(1u8);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -2194,18 +2357,20 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Lsr, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Lsr, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
         let value = dereference_address_to_operand(cpu, addr, index) >> Operand::new(1u8);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            indexed_addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -2216,8 +2381,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Lsr, addressing_mode::Accumulator> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Lsr, addressing_mode::Accumulator>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = Operand::new(cpu.acc.read()) >> Operand::new(1u8);
 
         Operations::new(
@@ -2233,14 +2400,18 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Lsr, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Lsr, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Lsr, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap() as u16;
         let value = dereference_address_to_operand(cpu, addr, 0) >> Operand::new(1u8);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -2251,17 +2422,19 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Lsr, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Lsr, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let indexed_addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), index);
         let value = dereference_address_to_operand(cpu, indexed_addr, 0) >> Operand::new(1u8);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            indexed_addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -2274,8 +2447,10 @@ impl Generate<Mos6502, Operations>
 
 // Ora
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ora, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Ora, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let lhs = Operand::new(cpu.acc.read());
         let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap(), 0);
         let value = lhs | rhs;
@@ -2292,10 +2467,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ora, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Ora, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
@@ -2304,11 +2479,7 @@ impl Generate<Mos6502, Operations>
         let value = lhs | rhs;
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -2322,10 +2493,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Ora, addressing_mode::AbsoluteIndexedWithY>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.y.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
@@ -2334,11 +2505,7 @@ impl Generate<Mos6502, Operations>
         let value = lhs | rhs;
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -2352,10 +2519,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Ora, addressing_mode::IndirectYIndexed>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let zpage_base_addr = self.addressing_mode.unwrap();
         let indirect_addr =
             dereference_indirect_indexed_address(cpu, zpage_base_addr, cpu.y.read());
@@ -2364,11 +2531,7 @@ impl Generate<Mos6502, Operations>
         let value = lhs | rhs;
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(zpage_base_addr as u16).contains(indirect_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, zpage_base_addr as u16, indirect_addr);
 
         Operations::new(
             self.offset(),
@@ -2382,8 +2545,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ora, addressing_mode::Immediate> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Ora, addressing_mode::Immediate>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let lhs = Operand::new(cpu.acc.read());
         let rhs = Operand::new(self.addressing_mode.unwrap());
         let value = lhs | rhs;
@@ -2400,10 +2565,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ora, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Ora, addressing_mode::XIndexedIndirect>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let indirect_addr =
             dereference_indexed_indirect_address(cpu, self.addressing_mode.unwrap(), cpu.x.read());
         let lhs = Operand::new(cpu.acc.read());
@@ -2422,8 +2587,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ora, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Ora, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let lhs = Operand::new(cpu.acc.read());
         let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap() as u16, 0);
         let value = lhs | rhs;
@@ -2440,10 +2607,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ora, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Ora, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let indexed_addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), index);
         let lhs = Operand::new(cpu.acc.read());
@@ -2464,15 +2631,19 @@ impl Generate<Mos6502, Operations>
 
 // Rol
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Rol, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Rol, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let lhs = dereference_address_to_operand(cpu, addr, 0);
         let value = lhs.rol(Operand::new(1u8), cpu.ps.carry);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -2483,19 +2654,21 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Rol, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Rol, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
         let lhs = dereference_address_to_operand(cpu, addr, index);
         let value = lhs.rol(Operand::new(1u8), cpu.ps.carry);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            indexed_addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -2506,8 +2679,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Rol, addressing_mode::Accumulator> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Rol, addressing_mode::Accumulator>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = Operand::new(cpu.acc.read()).rol(Operand::new(1u8), cpu.ps.carry);
 
         Operations::new(
@@ -2523,15 +2698,19 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Rol, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Rol, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Rol, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap() as u16;
         let lhs = dereference_address_to_operand(cpu, addr, 0);
         let value = lhs.rol(Operand::new(1u8), cpu.ps.carry);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -2542,18 +2721,20 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Rol, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Rol, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let indexed_addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), index);
         let lhs = dereference_address_to_operand(cpu, indexed_addr, 0);
         let value = lhs.rol(Operand::new(1u8), cpu.ps.carry);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            indexed_addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -2566,15 +2747,19 @@ impl Generate<Mos6502, Operations>
 
 // Ror
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ror, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Ror, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let value =
             dereference_address_to_operand(cpu, addr, 0).ror(Operand::new(1u8), cpu.ps.carry);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -2585,19 +2770,21 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ror, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Ror, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
         let value =
             dereference_address_to_operand(cpu, addr, index).ror(Operand::new(1u8), cpu.ps.carry);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            indexed_addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -2608,8 +2795,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ror, addressing_mode::Accumulator> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Ror, addressing_mode::Accumulator>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = Operand::new(cpu.acc.read()).ror(Operand::new(1u8), cpu.ps.carry);
 
         Operations::new(
@@ -2625,15 +2814,19 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ror, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ror, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Ror, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap() as u16;
         let value =
             dereference_address_to_operand(cpu, addr, 0).ror(Operand::new(1u8), cpu.ps.carry);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -2644,18 +2837,20 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ror, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Ror, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let indexed_addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), index);
         let value = dereference_address_to_operand(cpu, indexed_addr, 0)
             .ror(Operand::new(1u8), cpu.ps.carry);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            indexed_addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Carry, value.carry),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
@@ -2668,42 +2863,55 @@ impl Generate<Mos6502, Operations>
 
 // Branching
 
-fn branch_on_case(
+fn branch_on_case<B: Addressable<u16, u8>>(
     cond: bool,
     branch_offset: i8,
     inst_offset: usize,
     cycles: usize,
-    cpu: &Mos6502,
+    cpu: &Mos6502<B>,
 ) -> Operations {
-    let jmp_on_eq = (Wrapping(cpu.pc.read()) + Wrapping(branch_offset as u16)).0;
-    let mc = if cond {
-        vec![gen_write_16bit_register_microcode!(
-            WordRegisters::Pc,
-            // handle for underflow
-            jmp_on_eq
-        )]
-    } else {
-        vec![gen_write_16bit_register_microcode!(
-            WordRegisters::Pc,
-            // handle for underflow
-            cpu.pc.read().overflowing_add(inst_offset as u16).0
-        )]
-    };
+    // offsets are relative to the instruction following the branch.
+    let next_pc = cpu.pc.read().wrapping_add(inst_offset as u16);
+
+    // a branch that isn't taken steps over the instruction for its base cycles.
+    if !cond {
+        return Operations::new(
+            0,
+            cycles,
+            vec![gen_write_16bit_register_microcode!(
+                WordRegisters::Pc,
+                next_pc
+            )],
+        );
+    }
+
+    // sign-extend the offset so that backward branches wrap correctly.
+    let jmp_on_eq = (Wrapping(next_pc) + Wrapping(branch_offset as i16 as u16)).0;
 
-    // if the branch is true and that branch crosses a page boundary pay a 1 cycle penalty.
-    let branch_penalty = match (cond, Page::from(cpu.pc.read()).contains(jmp_on_eq)) {
-        (true, false) => 2,
-        (true, true) => 1,
-        _ => 0,
+    // a taken branch pays a 1 cycle penalty, and an additional cycle if that
+    // branch crosses a page boundary.
+    let branch_penalty = match cpu.fast_mode {
+        false if !Page::from(next_pc).contains(jmp_on_eq) => 2,
+        false => 1,
+        true => 0,
     };
 
-    Operations::new(0, cycles + branch_penalty, mc)
+    Operations::new(
+        0,
+        cycles + branch_penalty,
+        vec![gen_write_16bit_register_microcode!(
+            WordRegisters::Pc,
+            jmp_on_eq
+        )],
+    )
 }
 
 // Bcc
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bcc, addressing_mode::Relative> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Bcc, addressing_mode::Relative>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let offset = self.addressing_mode.unwrap();
 
         branch_on_case(!cpu.ps.carry, offset, self.offset(), self.cycles(), cpu)
@@ -2712,8 +2920,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bcc, addressing_mod
 
 // Bcs
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bcs, addressing_mode::Relative> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Bcs, addressing_mode::Relative>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let offset = self.addressing_mode.unwrap();
 
         branch_on_case(cpu.ps.carry, offset, self.offset(), self.cycles(), cpu)
@@ -2722,8 +2932,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bcs, addressing_mod
 
 // Beq
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Beq, addressing_mode::Relative> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Beq, addressing_mode::Relative>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let offset = self.addressing_mode.unwrap();
 
         branch_on_case(cpu.ps.zero, offset, self.offset(), self.cycles(), cpu)
@@ -2732,8 +2944,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Beq, addressing_mod
 
 // Bmi
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bmi, addressing_mode::Relative> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Bmi, addressing_mode::Relative>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let offset = self.addressing_mode.unwrap();
 
         branch_on_case(cpu.ps.negative, offset, self.offset(), self.cycles(), cpu)
@@ -2742,8 +2956,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bmi, addressing_mod
 
 // Bne
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bne, addressing_mode::Relative> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Bne, addressing_mode::Relative>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let offset = self.addressing_mode.unwrap();
 
         branch_on_case(!cpu.ps.zero, offset, self.offset(), self.cycles(), cpu)
@@ -2752,8 +2968,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bne, addressing_mod
 
 // Bpl
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bpl, addressing_mode::Relative> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Bpl, addressing_mode::Relative>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let offset = self.addressing_mode.unwrap();
 
         branch_on_case(!cpu.ps.negative, offset, self.offset(), self.cycles(), cpu)
@@ -2762,8 +2980,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bpl, addressing_mod
 
 // Bvc
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bvc, addressing_mode::Relative> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Bvc, addressing_mode::Relative>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let offset = self.addressing_mode.unwrap();
 
         branch_on_case(!cpu.ps.overflow, offset, self.offset(), self.cycles(), cpu)
@@ -2772,8 +2992,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bvc, addressing_mod
 
 // Bvs
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bvs, addressing_mode::Relative> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Bvs, addressing_mode::Relative>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let offset = self.addressing_mode.unwrap();
 
         branch_on_case(cpu.ps.overflow, offset, self.offset(), self.cycles(), cpu)
@@ -2782,8 +3004,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Bvs, addressing_mod
 
 // Clc
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Clc, addressing_mode::Implied> {
-    fn generate(&self, _: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Clc, addressing_mode::Implied>
+{
+    fn generate(&self, _: &Mos6502<B>) -> Operations {
         Operations::new(
             self.offset(),
             self.cycles(),
@@ -2794,8 +3018,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Clc, addressing_mod
 
 // Cld
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cld, addressing_mode::Implied> {
-    fn generate(&self, _: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Cld, addressing_mode::Implied>
+{
+    fn generate(&self, _: &Mos6502<B>) -> Operations {
         Operations::new(
             self.offset(),
             self.cycles(),
@@ -2806,8 +3032,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cld, addressing_mod
 
 // Cli
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cli, addressing_mode::Implied> {
-    fn generate(&self, _: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Cli, addressing_mode::Implied>
+{
+    fn generate(&self, _: &Mos6502<B>) -> Operations {
         Operations::new(
             self.offset(),
             self.cycles(),
@@ -2821,8 +3049,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cli, addressing_mod
 
 // Clv
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Clv, addressing_mode::Implied> {
-    fn generate(&self, _: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Clv, addressing_mode::Implied>
+{
+    fn generate(&self, _: &Mos6502<B>) -> Operations {
         Operations::new(
             self.offset(),
             self.cycles(),
@@ -2833,8 +3063,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Clv, addressing_mod
 
 // Cmp
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cmp, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Cmp, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap(), 0);
         let lhs = Operand::new(cpu.acc.read());
         let carry = lhs >= rhs;
@@ -2852,10 +3084,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cmp, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Cmp, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let base_addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(base_addr, index);
@@ -2865,11 +3097,7 @@ impl Generate<Mos6502, Operations>
         let diff = lhs - rhs;
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(base_addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, base_addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -2883,10 +3111,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Cmp, addressing_mode::AbsoluteIndexedWithY>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.y.read();
         let base_addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(base_addr, index);
@@ -2896,11 +3124,7 @@ impl Generate<Mos6502, Operations>
         let diff = lhs - rhs;
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(base_addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, base_addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -2914,10 +3138,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Cmp, addressing_mode::IndirectYIndexed>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.y.read();
         let base_addr = self.addressing_mode.unwrap();
         let indirect_addr = dereference_indirect_indexed_address(cpu, base_addr, index);
@@ -2927,11 +3151,7 @@ impl Generate<Mos6502, Operations>
         let diff = lhs - rhs;
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(base_addr as u16).contains(indirect_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, base_addr as u16, indirect_addr);
 
         Operations::new(
             self.offset(),
@@ -2945,8 +3165,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cmp, addressing_mode::Immediate> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Cmp, addressing_mode::Immediate>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addressing_mode::Immediate(am_value) = self.addressing_mode;
         let rhs = Operand::new(am_value);
         let lhs = Operand::new(cpu.acc.read());
@@ -2965,10 +3187,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cmp, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Cmp, addressing_mode::XIndexedIndirect>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let indirect_addr =
             dereference_indexed_indirect_address(cpu, self.addressing_mode.unwrap(), index);
@@ -2989,8 +3211,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cmp, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Cmp, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap() as u16, 0);
         let lhs = Operand::new(cpu.acc.read());
         let carry = lhs >= rhs;
@@ -3008,10 +3232,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cmp, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Cmp, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let base_addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), index);
         let rhs = dereference_address_to_operand(cpu, base_addr, 0);
@@ -3033,8 +3257,10 @@ impl Generate<Mos6502, Operations>
 
 // Cpx
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cpx, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Cpx, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap(), 0);
         let lhs = Operand::new(cpu.x.read());
         let carry = lhs >= rhs;
@@ -3052,8 +3278,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cpx, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cpx, addressing_mode::Immediate> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Cpx, addressing_mode::Immediate>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addressing_mode::Immediate(am_value) = self.addressing_mode;
         let rhs = Operand::new(am_value);
         let lhs = Operand::new(cpu.x.read());
@@ -3072,8 +3300,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cpx, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cpx, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Cpx, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap() as u16, 0);
         let lhs = Operand::new(cpu.x.read());
         let carry = lhs >= rhs;
@@ -3093,8 +3323,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cpx, addressing_mod
 
 // Cpy
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cpy, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Cpy, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap(), 0);
         let lhs = Operand::new(cpu.y.read());
         let carry = lhs >= rhs;
@@ -3112,8 +3344,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cpy, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cpy, addressing_mode::Immediate> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Cpy, addressing_mode::Immediate>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addressing_mode::Immediate(am_value) = self.addressing_mode;
         let rhs = Operand::new(am_value);
         let lhs = Operand::new(cpu.y.read());
@@ -3132,8 +3366,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cpy, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cpy, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Cpy, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let rhs = dereference_address_to_operand(cpu, self.addressing_mode.unwrap() as u16, 0);
         let lhs = Operand::new(cpu.y.read());
         let carry = lhs >= rhs;
@@ -3153,14 +3389,18 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Cpy, addressing_mod
 
 // Dec
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Dec, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Dec, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let value = dereference_address_to_operand(cpu, addr, 0) - Operand::new(1);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
@@ -3170,18 +3410,20 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Dec, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Dec, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
         let value = dereference_address_to_operand(cpu, indexed_addr, 0) - Operand::new(1);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            indexed_addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
@@ -3191,14 +3433,18 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Dec, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Dec, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap() as u16;
         let value = dereference_address_to_operand(cpu, addr, 0) - Operand::new(1);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
@@ -3208,18 +3454,20 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Dec, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Dec, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_zeropage_address(addr, index);
         let value = dereference_address_to_operand(cpu, indexed_addr, 0) - Operand::new(1);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            indexed_addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
@@ -3231,8 +3479,10 @@ impl Generate<Mos6502, Operations>
 
 // Dex
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Dex, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Dex, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = Operand::new(cpu.x.read()) - Operand::new(1);
 
         Operations::new(
@@ -3249,8 +3499,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Dex, addressing_mod
 
 // Dey
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Dey, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Dey, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = Operand::new(cpu.x.read()) - Operand::new(1);
 
         Operations::new(
@@ -3267,14 +3519,18 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Dey, addressing_mod
 
 // Inc
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Inc, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Inc, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let value = dereference_address_to_operand(cpu, addr, 0) + Operand::new(1);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
@@ -3284,18 +3540,20 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Inc, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Inc, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
         let value = dereference_address_to_operand(cpu, indexed_addr, 0) + Operand::new(1);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            indexed_addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
@@ -3305,14 +3563,18 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Inc, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Inc, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap() as u16;
         let value = dereference_address_to_operand(cpu, addr, 0) + Operand::new(1);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
@@ -3322,18 +3584,20 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Inc, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Inc, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_zeropage_address(addr, index);
         let value = dereference_address_to_operand(cpu, indexed_addr, 0) + Operand::new(1);
 
-        Operations::new(
+        rmw_operations(
+            cpu,
             self.offset(),
             self.cycles(),
+            indexed_addr,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
@@ -3345,8 +3609,10 @@ impl Generate<Mos6502, Operations>
 
 // Inx
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Inx, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Inx, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = Operand::new(cpu.x.read()) + Operand::new(1);
 
         Operations::new(
@@ -3363,8 +3629,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Inx, addressing_mod
 
 // Iny
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Iny, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Iny, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = Operand::new(cpu.y.read()) + Operand::new(1);
 
         Operations::new(
@@ -3381,8 +3649,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Iny, addressing_mod
 
 // Jmp
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Jmp, addressing_mode::Absolute> {
-    fn generate(&self, _: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Jmp, addressing_mode::Absolute>
+{
+    fn generate(&self, _: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
 
         Operations::new(
@@ -3393,16 +3663,27 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Jmp, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Jmp, addressing_mode::Indirect> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Jmp, addressing_mode::Indirect>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let indirect_addr = self.addressing_mode.unwrap();
+        let (msb_addr, variant_penalty) = match cpu.variant {
+            // the NMOS 6502 does not carry into the high byte of the pointer,
+            // fetching the high byte from the start of the same page.
+            Variant::Nmos => {
+                let [ll, hh] = indirect_addr.to_le_bytes();
+                (u16::from_le_bytes([ll.wrapping_add(1), hh]), 0)
+            }
+            Variant::Cmos => (indirect_addr.wrapping_add(1), 1),
+        };
         let lsb = cpu.address_map.read(indirect_addr);
-        let msb = cpu.address_map.read(indirect_addr + 1);
+        let msb = cpu.address_map.read(msb_addr);
         let addr = u16::from_le_bytes([lsb, msb]);
 
         Operations::new(
             0,
-            self.cycles(),
+            self.cycles() + variant_penalty,
             vec![gen_write_16bit_register_microcode!(WordRegisters::Pc, addr)],
         )
     }
@@ -3410,8 +3691,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Jmp, addressing_mod
 
 // Jsr
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Jsr, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Jsr, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
 
         // grab the stack pointer and stack pointer - 1 for storing the PC
@@ -3421,15 +3704,19 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Jsr, addressing_mod
         // Add 2 to the program counter and grab as little-endian bytes.
         let [pcl, pch] = cpu.pc.read().wrapping_add(2).to_le_bytes();
 
-        Operations::new(
+        // the return address is pushed on the 4th and 5th cycles.
+        Operations::scheduled(
             0,
             self.cycles(),
             vec![
-                gen_write_memory_microcode!(sph, pch),
-                gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
-                gen_write_memory_microcode!(spl, pcl),
-                gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
-                gen_write_16bit_register_microcode!(WordRegisters::Pc, addr),
+                (3, gen_write_memory_microcode!(sph, pch)),
+                (3, gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (4, gen_write_memory_microcode!(spl, pcl)),
+                (4, gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (
+                    5,
+                    gen_write_16bit_register_microcode!(WordRegisters::Pc, addr),
+                ),
             ],
         )
     }
@@ -3437,8 +3724,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Jsr, addressing_mod
 
 // Lda
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Lda, addressing_mode::Immediate> {
-    fn generate(&self, _: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Lda, addressing_mode::Immediate>
+{
+    fn generate(&self, _: &Mos6502<B>) -> Operations {
         let value = Operand::new(self.addressing_mode.unwrap());
 
         Operations::new(
@@ -3453,8 +3742,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Lda, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Lda, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Lda, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = dereference_address_to_operand(cpu, self.addressing_mode.unwrap() as u16, 0);
 
         Operations::new(
@@ -3469,10 +3760,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Lda, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Lda, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), index);
         let value = dereference_address_to_operand(cpu, addr as u16, 0);
@@ -3489,8 +3780,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Lda, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Lda, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addressing_mode::Absolute(addr) = self.addressing_mode;
         let value = Operand::new(cpu.address_map.read(addr));
         Operations::new(
@@ -3505,21 +3798,17 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Lda, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Lda, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
         let value = dereference_address_to_operand(cpu, addr, index);
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -3533,21 +3822,17 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Lda, addressing_mode::AbsoluteIndexedWithY>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.y.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
         let value = dereference_address_to_operand(cpu, indexed_addr, 0);
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -3561,21 +3846,17 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Lda, addressing_mode::IndirectYIndexed>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let zpage_base_addr = self.addressing_mode.unwrap();
         let indirect_addr =
             dereference_indirect_indexed_address(cpu, zpage_base_addr, cpu.y.read());
         let value = Operand::new(cpu.address_map.read(indirect_addr));
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(zpage_base_addr as u16).contains(indirect_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, zpage_base_addr as u16, indirect_addr);
 
         Operations::new(
             self.offset(),
@@ -3589,10 +3870,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Lda, addressing_mode::XIndexedIndirect>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let indirect_addr =
             dereference_indexed_indirect_address(cpu, self.addressing_mode.unwrap(), cpu.x.read());
         let value = Operand::new(cpu.address_map.read(indirect_addr));
@@ -3611,8 +3892,10 @@ impl Generate<Mos6502, Operations>
 
 // Ldx
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ldx, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Ldx, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let value = dereference_address_to_operand(cpu, addr, 0);
 
@@ -3628,21 +3911,17 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ldx, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Ldx, addressing_mode::AbsoluteIndexedWithY>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.y.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
         let value = dereference_address_to_operand(cpu, indexed_addr, 0);
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -3656,8 +3935,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ldx, addressing_mode::Immediate> {
-    fn generate(&self, _: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Ldx, addressing_mode::Immediate>
+{
+    fn generate(&self, _: &Mos6502<B>) -> Operations {
         let value = Operand::new(self.addressing_mode.unwrap());
 
         Operations::new(
@@ -3672,8 +3953,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ldx, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ldx, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Ldx, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = dereference_address_to_operand(cpu, self.addressing_mode.unwrap() as u16, 0);
 
         Operations::new(
@@ -3688,10 +3971,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ldx, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Ldx, addressing_mode::ZeroPageIndexedWithY>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.y.read();
         let addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), index);
         let value = dereference_address_to_operand(cpu, addr as u16, 0);
@@ -3710,8 +3993,10 @@ impl Generate<Mos6502, Operations>
 
 // Ldy
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ldy, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Ldy, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let value = dereference_address_to_operand(cpu, addr, 0);
 
@@ -3727,21 +4012,17 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ldy, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Ldy, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = self.addressing_mode.unwrap();
         let indexed_addr = add_index_to_address(addr, index);
         let value = dereference_address_to_operand(cpu, indexed_addr, 0);
 
         // if the branch crosses a page boundary pay a 1 cycle penalty.
-        let branch_penalty = if !Page::from(addr).contains(indexed_addr) {
-            1
-        } else {
-            0
-        };
+        let branch_penalty = page_cross_penalty(cpu, addr, indexed_addr);
 
         Operations::new(
             self.offset(),
@@ -3755,8 +4036,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ldy, addressing_mode::Immediate> {
-    fn generate(&self, _: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Ldy, addressing_mode::Immediate>
+{
+    fn generate(&self, _: &Mos6502<B>) -> Operations {
         let value = Operand::new(self.addressing_mode.unwrap());
 
         Operations::new(
@@ -3771,8 +4054,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ldy, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ldy, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Ldy, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = dereference_address_to_operand(cpu, self.addressing_mode.unwrap() as u16, 0);
 
         Operations::new(
@@ -3787,10 +4072,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Ldy, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Ldy, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), index);
         let value = dereference_address_to_operand(cpu, addr as u16, 0);
@@ -3809,8 +4094,10 @@ impl Generate<Mos6502, Operations>
 
 // Pha
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Pha, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Pha, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = cpu.acc.read();
         let sp = cpu.sp.read();
 
@@ -3827,9 +4114,12 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Pha, addressing_mod
 
 // Php
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Php, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
-        let value = cpu.ps.read();
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Php, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
+        // the pushed status always has the break and unused bits set.
+        let value = cpu.ps.read() | 0x30;
         let sp = cpu.sp.read();
 
         Operations::new(
@@ -3845,19 +4135,32 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Php, addressing_mod
 
 // Pla
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Pla, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Pla, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let sp = cpu.sp.read().overflowing_add(1).0;
         let value = dereference_address_to_operand(cpu, stack_pointer_from_byte_value(sp), 0);
 
-        Operations::new(
+        // the stack pointer is incremented on the 3rd cycle and the value
+        // pulled on the 4th.
+        Operations::scheduled(
             self.offset(),
             self.cycles(),
             vec![
-                gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1),
-                gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
-                gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
-                gen_write_8bit_register_microcode!(ByteRegisters::Acc, value.unwrap()),
+                (2, gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (
+                    3,
+                    gen_flag_set_microcode!(ProgramStatusFlags::Negative, value.negative),
+                ),
+                (
+                    3,
+                    gen_flag_set_microcode!(ProgramStatusFlags::Zero, value.zero),
+                ),
+                (
+                    3,
+                    gen_write_8bit_register_microcode!(ByteRegisters::Acc, value.unwrap()),
+                ),
             ],
         )
     }
@@ -3865,17 +4168,27 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Pla, addressing_mod
 
 // Plp
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Plp, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Plp, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let sp = cpu.sp.read().overflowing_add(1).0;
         let value = dereference_address_to_operand(cpu, stack_pointer_from_byte_value(sp), 0);
 
-        Operations::new(
+        // the stack pointer is incremented on the 3rd cycle and the status
+        // pulled on the 4th.
+        Operations::scheduled(
             self.offset(),
             self.cycles(),
             vec![
-                gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1),
-                gen_write_8bit_register_microcode!(ByteRegisters::Ps, value.unwrap()),
+                (2, gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (
+                    3,
+                    gen_write_8bit_register_microcode!(
+                        ByteRegisters::Ps,
+                        status_from_stack(value.unwrap())
+                    ),
+                ),
             ],
         )
     }
@@ -3883,11 +4196,13 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Plp, addressing_mod
 
 // Rti
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Rti, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Rti, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         // grab the program status
         let sp_psl: u16 = stack_pointer_from_byte_value(cpu.sp.read().wrapping_add(1));
-        let sp = cpu.address_map.read(sp_psl);
+        let ps = status_from_stack(cpu.address_map.read(sp_psl));
 
         // grab the stack pointer and stack pointer - 1 for storing the PC
         let sp_pcl: u16 = stack_pointer_from_byte_value(cpu.sp.read().wrapping_add(2));
@@ -3896,14 +4211,20 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Rti, addressing_mod
         let (lsb, hsb) = (cpu.address_map.read(sp_pcl), cpu.address_map.read(sp_pch));
         let ret_addr = u16::from_le_bytes([lsb, hsb]);
 
-        Operations::new(
-            self.offset(),
+        // the status is pulled on the 4th cycle and the return address over
+        // the 5th and 6th.
+        Operations::scheduled(
+            0, // returns directly to the address pulled from the stack
             self.cycles(),
             vec![
-                gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1),
-                gen_write_8bit_register_microcode!(ByteRegisters::Ps, sp),
-                gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 2),
-                gen_write_16bit_register_microcode!(WordRegisters::Pc, ret_addr),
+                (2, gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (3, gen_write_8bit_register_microcode!(ByteRegisters::Ps, ps)),
+                (3, gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (4, gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (
+                    5,
+                    gen_write_16bit_register_microcode!(WordRegisters::Pc, ret_addr),
+                ),
             ],
         )
     }
@@ -3911,8 +4232,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Rti, addressing_mod
 
 // Rts
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Rts, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Rts, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         // grab the stack pointer and stack pointer - 1 for storing the PC
         let spl: u16 = stack_pointer_from_byte_value(cpu.sp.read().wrapping_add(1));
         let sph: u16 = stack_pointer_from_byte_value(cpu.sp.read().wrapping_add(2));
@@ -3920,12 +4243,18 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Rts, addressing_mod
         let (lsb, hsb) = (cpu.address_map.read(spl), cpu.address_map.read(sph));
         let ret_addr = u16::from_le_bytes([lsb, hsb]);
 
-        Operations::new(
+        // the return address is pulled over the 4th and 5th cycles, with the
+        // program counter stepped past the JSR operand on the 6th.
+        Operations::scheduled(
             self.offset(),
             self.cycles(),
             vec![
-                gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 2),
-                gen_write_16bit_register_microcode!(WordRegisters::Pc, ret_addr),
+                (2, gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (3, gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (
+                    4,
+                    gen_write_16bit_register_microcode!(WordRegisters::Pc, ret_addr),
+                ),
             ],
         )
     }
@@ -3933,8 +4262,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Rts, addressing_mod
 
 // Sec
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sec, addressing_mode::Implied> {
-    fn generate(&self, _: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Sec, addressing_mode::Implied>
+{
+    fn generate(&self, _: &Mos6502<B>) -> Operations {
         Operations::new(
             self.offset(),
             self.cycles(),
@@ -3945,8 +4276,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sec, addressing_mod
 
 // Sed
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sed, addressing_mode::Implied> {
-    fn generate(&self, _: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Sed, addressing_mode::Implied>
+{
+    fn generate(&self, _: &Mos6502<B>) -> Operations {
         Operations::new(
             self.offset(),
             self.cycles(),
@@ -3957,8 +4290,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sed, addressing_mod
 
 // Sei
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sei, addressing_mode::Implied> {
-    fn generate(&self, _: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Sei, addressing_mode::Implied>
+{
+    fn generate(&self, _: &Mos6502<B>) -> Operations {
         Operations::new(
             self.offset(),
             self.cycles(),
@@ -3969,8 +4304,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sei, addressing_mod
 
 // Sta
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sta, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Sta, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addressing_mode::Absolute(addr) = self.addressing_mode;
         let acc_val = cpu.acc.read();
         Operations::new(
@@ -3981,10 +4318,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sta, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Sta, addressing_mode::AbsoluteIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let indexed_addr = add_index_to_address(self.addressing_mode.unwrap(), index);
         let acc_val = cpu.acc.read();
@@ -3996,10 +4333,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Sta, addressing_mode::AbsoluteIndexedWithY>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.y.read();
         let indexed_addr = add_index_to_address(self.addressing_mode.unwrap(), index);
         let acc_val = cpu.acc.read();
@@ -4012,10 +4349,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Sta, addressing_mode::IndirectYIndexed>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let indirect_addr =
             dereference_indirect_indexed_address(cpu, self.addressing_mode.unwrap(), cpu.y.read());
         let acc_val = cpu.acc.read();
@@ -4027,10 +4364,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Sta, addressing_mode::XIndexedIndirect>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let indirect_addr =
             dereference_indexed_indirect_address(cpu, self.addressing_mode.unwrap(), cpu.x.read());
         let acc_val = cpu.acc.read();
@@ -4042,8 +4379,10 @@ impl Generate<Mos6502, Operations>
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sta, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Sta, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap() as u16;
         let acc_val = cpu.acc.read();
 
@@ -4055,10 +4394,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sta, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Sta, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let indexed_addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), index);
         let acc_val = cpu.acc.read();
@@ -4073,8 +4412,10 @@ impl Generate<Mos6502, Operations>
 
 // Stx
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Stx, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Stx, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let value = cpu.x.read();
         Operations::new(
@@ -4085,8 +4426,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Stx, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Stx, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Stx, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap() as u16;
         let value = cpu.x.read();
 
@@ -4098,10 +4441,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Stx, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Stx, addressing_mode::ZeroPageIndexedWithY>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.y.read();
         let indexed_addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), index);
         let value = cpu.x.read();
@@ -4116,8 +4459,10 @@ impl Generate<Mos6502, Operations>
 
 // Sty
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sty, addressing_mode::Absolute> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Sty, addressing_mode::Absolute>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap();
         let value = cpu.y.read();
         Operations::new(
@@ -4128,8 +4473,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sty, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sty, addressing_mode::ZeroPage> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Sty, addressing_mode::ZeroPage>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let addr = self.addressing_mode.unwrap() as u16;
         let value = cpu.y.read();
 
@@ -4141,10 +4488,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Sty, addressing_mod
     }
 }
 
-impl Generate<Mos6502, Operations>
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
     for Instruction<mnemonic::Sty, addressing_mode::ZeroPageIndexedWithX>
 {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let index = cpu.x.read();
         let indexed_addr = add_index_to_zeropage_address(self.addressing_mode.unwrap(), index);
         let value = cpu.y.read();
@@ -4159,8 +4506,10 @@ impl Generate<Mos6502, Operations>
 
 // Tax
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Tax, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Tax, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = Operand::new(cpu.acc.read());
 
         Operations::new(
@@ -4177,8 +4526,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Tax, addressing_mod
 
 // Tay
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Tay, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Tay, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = Operand::new(cpu.acc.read());
 
         Operations::new(
@@ -4195,8 +4546,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Tay, addressing_mod
 
 // Tsx
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Tsx, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Tsx, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = Operand::new(cpu.sp.read());
 
         Operations::new(
@@ -4213,8 +4566,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Tsx, addressing_mod
 
 // Txa
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Txa, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Txa, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = Operand::new(cpu.x.read());
 
         Operations::new(
@@ -4231,8 +4586,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Txa, addressing_mod
 
 // Tsx
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Txs, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Txs, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = Operand::new(cpu.x.read());
 
         Operations::new(
@@ -4248,8 +4605,10 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Txs, addressing_mod
 
 // Tya
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Tya, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Tya, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
         let value = Operand::new(cpu.y.read());
 
         Operations::new(
@@ -4268,36 +4627,53 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Tya, addressing_mod
 
 // Brk
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Brk, addressing_mode::Implied> {
-    fn generate(&self, cpu: &Mos6502) -> Operations {
-        let ps = cpu.ps.read();
+/// BRK is a single byte opcode that is followed by a padding byte, often used
+/// as a break mark. The return address pushed to the stack skips the padding
+/// byte, causing RTI to resume at the instruction following it.
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Brk, addressing_mode::Implied>
+{
+    fn generate(&self, cpu: &Mos6502<B>) -> Operations {
+        // the pushed status always has the break flag set, distinguishing a
+        // BRK from a hardware interrupt.
+        let ps = {
+            let mut ps = cpu.ps;
+            ps.brk = true;
+            ps.read()
+        };
 
-        let sp_pcl: u16 = stack_pointer_from_byte_value(cpu.sp.read());
-        let sp_pch: u16 = stack_pointer_from_byte_value(cpu.sp.read().wrapping_sub(1));
+        let sp_pch: u16 = stack_pointer_from_byte_value(cpu.sp.read());
+        let sp_pcl: u16 = stack_pointer_from_byte_value(cpu.sp.read().wrapping_sub(1));
         let sp_ps: u16 = stack_pointer_from_byte_value(cpu.sp.read().wrapping_sub(2));
 
-        // Add 1 to the program counter and grab as little-endian bytes.
-        let [pcl, pch] = cpu.pc.read().wrapping_add(1).to_le_bytes();
+        // Add 2 to the program counter, skipping the padding byte, and grab as
+        // little-endian bytes.
+        let [pcl, pch] = cpu.pc.read().wrapping_add(2).to_le_bytes();
 
         // Grab IRQ/Brk vector
-        let irq_vector = u16::from_le_bytes([
-            cpu.address_map.read(IRQ_VECTOR_LL),
-            cpu.address_map.read(IRQ_VECTOR_HH),
-        ]);
+        let irq_vector = cpu.address_map.read_u16(IRQ_VECTOR_LL);
 
-        Operations::new(
+        // the return address and status are pushed on the 3rd through 5th
+        // cycles, with the vector fetched over the remaining two.
+        Operations::scheduled(
             0, // manually modified in the instruction
             self.cycles(),
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Break, true),
-                gen_flag_set_microcode!(ProgramStatusFlags::Interrupt, true),
-                gen_write_memory_microcode!(sp_pcl, pcl),
-                gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
-                gen_write_memory_microcode!(sp_pch, pch),
-                gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
-                gen_write_memory_microcode!(sp_ps, ps), // PS Register
-                gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
-                gen_write_16bit_register_microcode!(WordRegisters::Pc, irq_vector),
+                (2, gen_write_memory_microcode!(sp_pch, pch)),
+                (2, gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (3, gen_write_memory_microcode!(sp_pcl, pcl)),
+                (3, gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (4, gen_write_memory_microcode!(sp_ps, ps)), // PS Register
+                (4, gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (4, gen_flag_set_microcode!(ProgramStatusFlags::Break, true)),
+                (
+                    4,
+                    gen_flag_set_microcode!(ProgramStatusFlags::Interrupt, true),
+                ),
+                (
+                    6,
+                    gen_write_16bit_register_microcode!(WordRegisters::Pc, irq_vector),
+                ),
             ],
         )
     }
@@ -4305,8 +4681,36 @@ impl Generate<Mos6502, Operations> for Instruction<mnemonic::Brk, addressing_mod
 
 // Nop
 
-impl Generate<Mos6502, Operations> for Instruction<mnemonic::Nop, addressing_mode::Implied> {
-    fn generate(&self, _: &Mos6502) -> Operations {
+impl<B: Addressable<u16, u8>> Generate<Mos6502<B>, Operations>
+    for Instruction<mnemonic::Nop, addressing_mode::Implied>
+{
+    fn generate(&self, _: &Mos6502<B>) -> Operations {
         Operations::new(self.offset(), self.cycles(), vec![])
     }
 }
+
+/// Returns the operations of the undocumented NMOS NOPs that consume an
+/// operand, or `None` if the opcode is not one of them. These read and
+/// discard their operand, advancing the PC past it.
+pub fn illegal_nop<B: Addressable<u16, u8>>(cpu: &Mos6502<B>, opcode: u8) -> Option<Operations> {
+    match opcode {
+        // immediate
+        0x80 | 0x82 | 0x89 | 0xc2 | 0xe2 => Some(Operations::new(2, 2, vec![])),
+        // zeropage
+        0x04 | 0x44 | 0x64 => Some(Operations::new(2, 3, vec![])),
+        // zeropage indexed with x
+        0x14 | 0x34 | 0x54 | 0x74 | 0xd4 | 0xf4 => Some(Operations::new(2, 4, vec![])),
+        // absolute
+        0x0c => Some(Operations::new(3, 4, vec![])),
+        // absolute indexed with x
+        0x1c | 0x3c | 0x5c | 0x7c | 0xdc | 0xfc => {
+            let pc = cpu.pc.read();
+            let base_addr = cpu.address_map.read_u16(pc.wrapping_add(1));
+            let indexed_addr = base_addr.wrapping_add(cpu.x.read() as u16);
+            let branch_penalty = page_cross_penalty(cpu, base_addr, indexed_addr);
+
+            Some(Operations::new(3, 4 + branch_penalty, vec![]))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,100 @@
+use crate::cpu::mos6502::{
+    operations::Operations,
+    register::{ByteRegisters, GeneralPurpose, GpRegister, ProgramStatusFlags},
+    Generate, Mos6502,
+};
+use crate::cpu::register::Register;
+use isa_mos6502::{addressing_mode, mnemonic, Instruction, InstructionVariant};
+
+/// Generates the accumulator-mode operation for the given mnemonic against a
+/// cpu with the provided accumulator value and carry flag.
+fn generate_with_acc_and_carry<M>(mnemonic: M, acc: u8, carry: bool) -> Operations
+where
+    Instruction<M, addressing_mode::Accumulator>: Into<InstructionVariant>,
+{
+    let mut cpu =
+        Mos6502::default().with_gp_register(GpRegister::Acc, GeneralPurpose::with_value(acc));
+    cpu.ps.carry = carry;
+    let op: InstructionVariant = Instruction::new(mnemonic, addressing_mode::Accumulator).into();
+
+    op.generate(&cpu)
+}
+
+/// Returns the expected operation for an accumulator-mode shift or rotate
+/// that sets the flags and accumulator to the provided values.
+fn expected_operations(carry: bool, negative: bool, zero: bool, acc: u8) -> Operations {
+    Operations::new(
+        1,
+        2,
+        vec![
+            gen_flag_set_microcode!(ProgramStatusFlags::Carry, carry),
+            gen_flag_set_microcode!(ProgramStatusFlags::Negative, negative),
+            gen_flag_set_microcode!(ProgramStatusFlags::Zero, zero),
+            gen_write_8bit_register_microcode!(ByteRegisters::Acc, acc),
+        ],
+    )
+}
+
+#[test]
+fn asl_should_shift_the_high_bit_into_carry_and_set_zero() {
+    assert_eq!(
+        expected_operations(true, false, true, 0x00),
+        generate_with_acc_and_carry(mnemonic::Asl, 0x80, false)
+    );
+}
+
+#[test]
+fn asl_should_not_shift_in_the_existing_carry() {
+    assert_eq!(
+        expected_operations(false, true, false, 0x82),
+        generate_with_acc_and_carry(mnemonic::Asl, 0x41, true)
+    );
+}
+
+#[test]
+fn lsr_should_shift_the_low_bit_into_carry_and_set_zero() {
+    assert_eq!(
+        expected_operations(true, false, true, 0x00),
+        generate_with_acc_and_carry(mnemonic::Lsr, 0x01, false)
+    );
+}
+
+#[test]
+fn lsr_should_not_shift_in_the_existing_carry() {
+    assert_eq!(
+        expected_operations(false, false, false, 0x41),
+        generate_with_acc_and_carry(mnemonic::Lsr, 0x82, true)
+    );
+}
+
+#[test]
+fn rol_should_rotate_the_carry_into_the_low_bit() {
+    assert_eq!(
+        expected_operations(false, false, false, 0x01),
+        generate_with_acc_and_carry(mnemonic::Rol, 0x00, true)
+    );
+}
+
+#[test]
+fn rol_should_rotate_the_high_bit_into_carry_and_set_zero() {
+    assert_eq!(
+        expected_operations(true, false, true, 0x00),
+        generate_with_acc_and_carry(mnemonic::Rol, 0x80, false)
+    );
+}
+
+#[test]
+fn ror_should_rotate_the_carry_into_the_high_bit() {
+    assert_eq!(
+        expected_operations(false, true, false, 0x80),
+        generate_with_acc_and_carry(mnemonic::Ror, 0x00, true)
+    );
+}
+
+#[test]
+fn ror_should_rotate_the_low_bit_into_carry_and_set_zero() {
+    assert_eq!(
+        expected_operations(true, false, true, 0x00),
+        generate_with_acc_and_carry(mnemonic::Ror, 0x01, false)
+    );
+}
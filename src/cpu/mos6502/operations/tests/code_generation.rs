@@ -1,5 +1,5 @@
 use crate::address_map::{
-    memory::{Memory, ReadOnly},
+    memory::{Memory, ReadOnly, ReadWrite},
     Addressable,
 };
 use crate::cpu::{
@@ -10,10 +10,11 @@ use crate::cpu::{
             ByteRegisters, GeneralPurpose, GpRegister, ProcessorStatus, ProgramCounter,
             ProgramStatusFlags, StackPointer, WordRegisters,
         },
-        Generate, Mos6502,
+        Generate, Mos6502, Variant,
     },
     register::Register,
 };
+use crate::tests::assert_generates;
 use isa_mos6502::{addressing_mode, mnemonic, Instruction, InstructionVariant};
 
 // Adc
@@ -559,8 +560,8 @@ fn should_generate_bcc_machine_code_with_branch_penalty() {
         Instruction::new(mnemonic::Bcc, addressing_mode::Relative(8)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address
-    let pc = cpu.pc.read() + 8;
+    // next pc + relative address
+    let pc = cpu.pc.read() + 2 + 8;
 
     assert_eq!(
         Operations::new(
@@ -581,8 +582,8 @@ fn should_generate_bcc_machine_code_with_branch_and_page_penalty() {
         Instruction::new(mnemonic::Bcc, addressing_mode::Relative(-8)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address
-    let pc = cpu.pc.read() - 8;
+    // next pc - relative address
+    let pc = cpu.pc.read() + 2 - 8;
 
     assert_eq!(
         Operations::new(
@@ -594,6 +595,30 @@ fn should_generate_bcc_machine_code_with_branch_and_page_penalty() {
     );
 }
 
+#[test]
+fn should_generate_bcc_machine_code_without_penalties_in_fast_mode() {
+    let mut cpu = Mos6502::default()
+        .with_pc_register(ProgramCounter::with_value(0x6000))
+        .with_fast_mode(true);
+    cpu.ps.carry = false;
+
+    let op: InstructionVariant =
+        Instruction::new(mnemonic::Bcc, addressing_mode::Relative(-8)).into();
+    let mc = op.generate(&cpu);
+
+    // next pc - relative address
+    let pc = cpu.pc.read() + 2 - 8;
+
+    assert_eq!(
+        Operations::new(
+            0,
+            2,
+            vec![gen_write_16bit_register_microcode!(WordRegisters::Pc, pc)]
+        ),
+        mc
+    );
+}
+
 #[test]
 fn should_generate_bcc_machine_code_with_no_jump() {
     let mut cpu = Mos6502::default().with_pc_register(ProgramCounter::with_value(0x6000));
@@ -615,6 +640,29 @@ fn should_generate_bcc_machine_code_with_no_jump() {
     );
 }
 
+#[test]
+fn should_generate_bcc_machine_code_for_base_cycles_when_not_taken_regardless_of_target_page() {
+    for &(pc, offset) in [(0x6000, 8), (0x6000, -8), (0x60f0, 0x20)].iter() {
+        let mut cpu = Mos6502::default().with_pc_register(ProgramCounter::with_value(pc));
+        cpu.ps.carry = true;
+
+        let op: InstructionVariant =
+            Instruction::new(mnemonic::Bcc, addressing_mode::Relative(offset)).into();
+
+        assert_eq!(
+            Operations::new(
+                0,
+                2,
+                vec![gen_write_16bit_register_microcode!(
+                    WordRegisters::Pc,
+                    pc + 2
+                )]
+            ),
+            op.generate(&cpu)
+        );
+    }
+}
+
 // Bcs
 
 #[test]
@@ -626,8 +674,8 @@ fn should_generate_bcs_machine_code_with_branch_penalty() {
         Instruction::new(mnemonic::Bcs, addressing_mode::Relative(8)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address
-    let pc = cpu.pc.read() + 8;
+    // next pc + relative address
+    let pc = cpu.pc.read() + 2 + 8;
 
     assert_eq!(
         Operations::new(
@@ -648,8 +696,8 @@ fn should_generate_bcs_machine_code_with_branch_and_page_penalty() {
         Instruction::new(mnemonic::Bcs, addressing_mode::Relative(-8)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address
-    let pc = cpu.pc.read() - 8;
+    // next pc - relative address
+    let pc = cpu.pc.read() + 2 - 8;
 
     assert_eq!(
         Operations::new(
@@ -693,8 +741,8 @@ fn should_generate_beq_machine_code_with_branch_penalty() {
         Instruction::new(mnemonic::Beq, addressing_mode::Relative(8)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address
-    let pc = cpu.pc.read() + 8;
+    // next pc + relative address
+    let pc = cpu.pc.read() + 2 + 8;
 
     assert_eq!(
         Operations::new(
@@ -715,8 +763,8 @@ fn should_generate_beq_machine_code_with_branch_and_page_penalty() {
         Instruction::new(mnemonic::Beq, addressing_mode::Relative(-8)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address
-    let pc = cpu.pc.read() - 8;
+    // next pc - relative address
+    let pc = cpu.pc.read() + 2 - 8;
 
     assert_eq!(
         Operations::new(
@@ -760,8 +808,8 @@ fn should_generate_bmi_machine_code_with_branch_penalty() {
         Instruction::new(mnemonic::Bmi, addressing_mode::Relative(8)).into();
     let mc = op.generate(&cpu);
 
-    // pc + relative address
-    let pc = cpu.pc.read() + 8;
+    // next pc + relative address
+    let pc = cpu.pc.read() + 2 + 8;
 
     assert_eq!(
         Operations::new(
@@ -782,8 +830,8 @@ fn should_generate_bmi_machine_code_with_branch_and_page_penalty() {
         Instruction::new(mnemonic::Bmi, addressing_mode::Relative(-8)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address
-    let pc = cpu.pc.read() - 8;
+    // next pc - relative address
+    let pc = cpu.pc.read() + 2 - 8;
 
     assert_eq!(
         Operations::new(
@@ -864,6 +912,29 @@ fn should_generate_zeropage_addressing_mode_bit_machine_code() {
     );
 }
 
+#[test]
+fn should_generate_bit_machine_code_with_flags_from_memory_and_zero_from_result() {
+    let mut cpu =
+        Mos6502::default().with_gp_register(GpRegister::Acc, GeneralPurpose::with_value(0x00));
+    cpu.address_map.write(0x00ff, 0xc0).unwrap();
+    let op: InstructionVariant =
+        Instruction::new(mnemonic::Bit, addressing_mode::ZeroPage(0xff)).into();
+    let mc = op.generate(&cpu);
+
+    assert_eq!(
+        Operations::new(
+            2,
+            3,
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Overflow, true),
+                gen_flag_set_microcode!(ProgramStatusFlags::Zero, true),
+            ]
+        ),
+        mc
+    );
+}
+
 // Bne
 
 #[test]
@@ -875,8 +946,8 @@ fn should_generate_bne_machine_code_with_branch_penalty() {
         Instruction::new(mnemonic::Bne, addressing_mode::Relative(8)).into();
     let mc = op.generate(&cpu);
 
-    // pc + relative address
-    let pc = cpu.pc.read() + 8;
+    // next pc + relative address
+    let pc = cpu.pc.read() + 2 + 8;
 
     assert_eq!(
         Operations::new(
@@ -897,8 +968,8 @@ fn should_generate_bne_machine_code_with_branch_and_page_penalty() {
         Instruction::new(mnemonic::Bne, addressing_mode::Relative(-8)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address
-    let pc = cpu.pc.read() - 8;
+    // next pc - relative address
+    let pc = cpu.pc.read() + 2 - 8;
 
     assert_eq!(
         Operations::new(
@@ -942,8 +1013,8 @@ fn should_generate_bpl_machine_code_with_branch_penalty() {
         Instruction::new(mnemonic::Bpl, addressing_mode::Relative(8)).into();
     let mc = op.generate(&cpu);
 
-    // pc + relative address
-    let pc = cpu.pc.read() + 8;
+    // next pc + relative address
+    let pc = cpu.pc.read() + 2 + 8;
 
     assert_eq!(
         Operations::new(
@@ -964,8 +1035,8 @@ fn should_generate_bpl_machine_code_with_branch_and_page_penalty() {
         Instruction::new(mnemonic::Bpl, addressing_mode::Relative(-8)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address
-    let pc = cpu.pc.read() - 8;
+    // next pc - relative address
+    let pc = cpu.pc.read() + 2 - 8;
 
     assert_eq!(
         Operations::new(
@@ -1020,23 +1091,32 @@ fn should_generate_implied_addressing_mode_brk_machine_code() {
     let op: InstructionVariant = Instruction::new(mnemonic::Brk, addressing_mode::Implied).into();
     let mc = op.generate(&cpu);
 
-    // expect unused flag to be set only for status register on stack.
-    let expected_ps_on_stack = ProcessorStatus::with_value(0b00100000);
+    // expect unused and break flags to be set for status register on stack.
+    let expected_ps_on_stack = ProcessorStatus::with_value(0b00110000);
 
     assert_eq!(
-        Operations::new(
+        Operations::scheduled(
             0, // PC controlled by the instruction
             7,
             vec![
-                gen_flag_set_microcode!(ProgramStatusFlags::Break, true),
-                gen_flag_set_microcode!(ProgramStatusFlags::Interrupt, true),
-                gen_write_memory_microcode!(0x01ff, 0x35), // PC (LL + 1)
-                gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
-                gen_write_memory_microcode!(0x01fe, 0x12), // PC (HH)
-                gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
-                gen_write_memory_microcode!(0x01fd, u8::from(expected_ps_on_stack)), // PS Register
-                gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
-                gen_write_16bit_register_microcode!(WordRegisters::Pc, 0x5678),
+                (2, gen_write_memory_microcode!(0x01fd, 0x12)), // PC (HH)
+                (2, gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (3, gen_write_memory_microcode!(0x01fc, 0x36)), // PC (LL + 2)
+                (3, gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (
+                    4,
+                    gen_write_memory_microcode!(0x01fb, u8::from(expected_ps_on_stack))
+                ), // PS Register
+                (4, gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (4, gen_flag_set_microcode!(ProgramStatusFlags::Break, true)),
+                (
+                    4,
+                    gen_flag_set_microcode!(ProgramStatusFlags::Interrupt, true)
+                ),
+                (
+                    6,
+                    gen_write_16bit_register_microcode!(WordRegisters::Pc, 0x5678)
+                ),
             ]
         ),
         mc
@@ -1054,8 +1134,8 @@ fn should_generate_bvc_machine_code_with_branch_penalty() {
         Instruction::new(mnemonic::Bvc, addressing_mode::Relative(8)).into();
     let mc = op.generate(&cpu);
 
-    // pc + relative address
-    let pc = cpu.pc.read() + 8;
+    // next pc + relative address
+    let pc = cpu.pc.read() + 2 + 8;
 
     assert_eq!(
         Operations::new(
@@ -1076,8 +1156,8 @@ fn should_generate_bvc_machine_code_with_branch_and_page_penalty() {
         Instruction::new(mnemonic::Bvc, addressing_mode::Relative(-8)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address
-    let pc = cpu.pc.read() - 8;
+    // next pc - relative address
+    let pc = cpu.pc.read() + 2 - 8;
 
     assert_eq!(
         Operations::new(
@@ -1121,8 +1201,8 @@ fn should_generate_bvs_machine_code_with_branch_penalty() {
         Instruction::new(mnemonic::Bvs, addressing_mode::Relative(8)).into();
     let mc = op.generate(&cpu);
 
-    // pc + relative address
-    let pc = cpu.pc.read() + 8;
+    // next pc + relative address
+    let pc = cpu.pc.read() + 2 + 8;
 
     assert_eq!(
         Operations::new(
@@ -1143,8 +1223,8 @@ fn should_generate_bvs_machine_code_with_branch_and_page_penalty() {
         Instruction::new(mnemonic::Bvs, addressing_mode::Relative(-8)).into();
     let mc = op.generate(&cpu);
 
-    // pc - relative address
-    let pc = cpu.pc.read() - 8;
+    // next pc - relative address
+    let pc = cpu.pc.read() + 2 - 8;
 
     assert_eq!(
         Operations::new(
@@ -1907,7 +1987,7 @@ fn should_generate_absolute_addressing_mode_inc_machine_code() {
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, false),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_memory_microcode!(0x01ff, 0x06),
+                gen_write_memory_microcode!(0x01ff, 0x06)
             ]
         ),
         mc
@@ -2030,19 +2110,19 @@ fn should_generate_implied_addressing_mode_iny_machine_code() {
 
 #[test]
 fn should_generate_absolute_addressing_mode_jmp_machine_code() {
-    let cpu = Mos6502::default();
     let addr = 0x0100;
-    let op: InstructionVariant =
-        Instruction::new(mnemonic::Jmp, addressing_mode::Absolute(addr)).into();
-    let mc = op.generate(&cpu);
 
-    assert_eq!(
+    assert_generates(
+        InstructionVariant::from(Instruction::new(
+            mnemonic::Jmp,
+            addressing_mode::Absolute(addr),
+        )),
+        &Mos6502::default(),
         Operations::new(
             0,
             3,
-            vec![gen_write_16bit_register_microcode!(WordRegisters::Pc, addr)]
+            vec![gen_write_16bit_register_microcode!(WordRegisters::Pc, addr)],
         ),
-        mc
     );
 }
 
@@ -2070,6 +2150,47 @@ fn should_generate_indirect_addressing_mode_jmp_machine_code() {
     );
 }
 
+#[test]
+fn should_generate_indirect_jmp_machine_code_across_a_page_boundary_per_variant() {
+    let mut cpu = Mos6502::default()
+        .register_address_space(
+            0x3000..=0x31ff,
+            Memory::<ReadWrite, u16, u8>::new(0x3000, 0x31ff),
+        )
+        .unwrap();
+    cpu.address_map.write(0x30ff, 0x34).unwrap();
+    cpu.address_map.write(0x3000, 0x12).unwrap();
+    cpu.address_map.write(0x3100, 0x56).unwrap();
+    let op: InstructionVariant =
+        Instruction::new(mnemonic::Jmp, addressing_mode::Indirect(0x30ff)).into();
+
+    // NMOS fetches the high byte from the start of the same page.
+    assert_eq!(
+        Operations::new(
+            0,
+            5,
+            vec![gen_write_16bit_register_microcode!(
+                WordRegisters::Pc,
+                0x1234
+            )]
+        ),
+        op.generate(&cpu.clone().with_variant(Variant::Nmos))
+    );
+
+    // CMOS crosses the page, taking an additional cycle.
+    assert_eq!(
+        Operations::new(
+            0,
+            6,
+            vec![gen_write_16bit_register_microcode!(
+                WordRegisters::Pc,
+                0x5634
+            )]
+        ),
+        op.generate(&cpu.with_variant(Variant::Cmos))
+    );
+}
+
 // Jsr
 
 #[test]
@@ -2085,15 +2206,18 @@ fn should_generate_absolute_addressing_mode_jsr_machine_code() {
     let [pcl, pch] = cpu.pc.read().wrapping_add(2).to_le_bytes();
 
     assert_eq!(
-        Operations::new(
+        Operations::scheduled(
             0, // offset modified directly by instruction
             6,
             vec![
-                gen_write_memory_microcode!(sph, pch),
-                gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
-                gen_write_memory_microcode!(spl, pcl),
-                gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
-                gen_write_16bit_register_microcode!(WordRegisters::Pc, addr)
+                (3, gen_write_memory_microcode!(sph, pch)),
+                (3, gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (4, gen_write_memory_microcode!(spl, pcl)),
+                (4, gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1)),
+                (
+                    5,
+                    gen_write_16bit_register_microcode!(WordRegisters::Pc, addr)
+                )
             ]
         ),
         mc
@@ -2104,22 +2228,21 @@ fn should_generate_absolute_addressing_mode_jsr_machine_code() {
 
 #[test]
 fn should_generate_immediate_addressing_mode_lda_machine_code() {
-    let cpu = Mos6502::default();
-    let op: InstructionVariant =
-        Instruction::new(mnemonic::Lda, addressing_mode::Immediate(0xff)).into();
-    let mc = op.generate(&cpu);
-
-    assert_eq!(
+    assert_generates(
+        InstructionVariant::from(Instruction::new(
+            mnemonic::Lda,
+            addressing_mode::Immediate(0xff),
+        )),
+        &Mos6502::default(),
         Operations::new(
             2,
             2,
             vec![
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
-                gen_write_8bit_register_microcode!(ByteRegisters::Acc, 0xff)
-            ]
+                gen_write_8bit_register_microcode!(ByteRegisters::Acc, 0xff),
+            ],
         ),
-        mc
     );
 }
 
@@ -2937,7 +3060,7 @@ fn should_generate_implied_addressing_mode_pha_machine_code() {
             3,
             vec![
                 // should write to the top of the stack
-                gen_write_memory_microcode!(0x01ff, 0xff),
+                gen_write_memory_microcode!(0x01fd, 0xff),
                 gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
             ]
         ),
@@ -2961,8 +3084,8 @@ fn should_generate_implied_addressing_mode_php_machine_code() {
             1,
             3,
             vec![
-                // should write to the top of the stack
-                gen_write_memory_microcode!(0x01ff, 0x55),
+                // should write to the top of the stack with break and unused set
+                gen_write_memory_microcode!(0x01fd, 0x75),
                 gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
             ]
         ),
@@ -2988,9 +3111,8 @@ fn should_generate_implied_addressing_mode_pla_machine_code() {
         vec![
             vec![],
             vec![],
-            vec![],
+            vec![gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1)],
             vec![
-                gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1),
                 gen_flag_set_microcode!(ProgramStatusFlags::Negative, true),
                 gen_flag_set_microcode!(ProgramStatusFlags::Zero, false),
                 gen_write_8bit_register_microcode!(ByteRegisters::Acc, 0xff),
@@ -3019,10 +3141,10 @@ fn should_generate_implied_addressing_mode_plp_machine_code() {
         vec![
             vec![],
             vec![],
-            vec![],
+            vec![gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1)],
             vec![
-                gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1),
-                gen_write_8bit_register_microcode!(ByteRegisters::Ps, 0x55),
+                // break is discarded and unused is set.
+                gen_write_8bit_register_microcode!(ByteRegisters::Ps, 0x65),
                 gen_inc_16bit_register_microcode!(WordRegisters::Pc, 1)
             ]
         ],
@@ -3334,17 +3456,21 @@ fn should_generate_implied_addressing_mode_rti_machine_code() {
     let mc = op.generate(&cpu);
 
     assert_eq!(
-        Operations::new(
-            1,
-            6,
+        vec![
+            vec![],
+            vec![],
+            vec![gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1)],
             vec![
-                gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1),
                 gen_write_8bit_register_microcode!(ByteRegisters::Ps, 0x20),
-                gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 2),
-                gen_write_16bit_register_microcode!(WordRegisters::Pc, 0x1234)
+                gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1)
+            ],
+            vec![gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1)],
+            vec![
+                gen_write_16bit_register_microcode!(WordRegisters::Pc, 0x1234),
+                gen_inc_16bit_register_microcode!(WordRegisters::Pc, 0)
             ]
-        ),
-        mc
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
     );
 }
 
@@ -3360,15 +3486,18 @@ fn should_generate_implied_addressing_mode_rts_machine_code() {
     let mc = op.generate(&cpu);
 
     assert_eq!(
-        Operations::new(
-            1,
-            6,
-            vec![
-                gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 2),
-                gen_write_16bit_register_microcode!(WordRegisters::Pc, 0x6002)
-            ]
-        ),
-        mc
+        vec![
+            vec![],
+            vec![],
+            vec![gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1)],
+            vec![gen_inc_8bit_register_microcode!(ByteRegisters::Sp, 1)],
+            vec![gen_write_16bit_register_microcode!(
+                WordRegisters::Pc,
+                0x6002
+            )],
+            vec![gen_inc_16bit_register_microcode!(WordRegisters::Pc, 1)]
+        ],
+        Into::<Vec<Vec<Microcode>>>::into(mc)
     );
 }
 
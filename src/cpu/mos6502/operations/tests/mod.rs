@@ -1,2 +1,125 @@
 #[cfg(test)]
+mod accumulator_shifts;
+#[cfg(test)]
 mod code_generation;
+
+use crate::cpu::mos6502::{
+    microcode::Microcode,
+    operations::{instruction_effects, InstructionEffects, Operations},
+    register::{ByteRegisters, ProgramStatusFlags, WordRegisters},
+    Generate, Mos6502,
+};
+use isa_mos6502::{addressing_mode, mnemonic, Instruction, InstructionVariant};
+
+#[test]
+fn should_treat_zero_cycle_operations_as_a_single_cycle() {
+    let mcs: Vec<Vec<Microcode>> = Operations::new(1, 0, vec![]).into();
+
+    assert_eq!(
+        vec![vec![gen_inc_16bit_register_microcode!(
+            WordRegisters::Pc,
+            1
+        )]],
+        mcs
+    );
+}
+
+#[test]
+fn should_write_memory_on_the_final_cycle_of_a_store() {
+    let cpu = Mos6502::default();
+    let op: InstructionVariant =
+        Instruction::new(mnemonic::Sta, addressing_mode::Absolute(0x0200)).into();
+    let mcs: Vec<Vec<Microcode>> = op.generate(&cpu).into();
+
+    assert_eq!(4, mcs.len());
+    assert!(mcs[..3].iter().all(|cycle| cycle.is_empty()));
+    assert_eq!(
+        vec![
+            gen_write_memory_microcode!(0x0200, 0x00),
+            gen_inc_16bit_register_microcode!(WordRegisters::Pc, 3)
+        ],
+        mcs[3]
+    );
+}
+
+#[test]
+fn should_push_the_jsr_return_address_on_the_fourth_and_fifth_cycles() {
+    let cpu = Mos6502::default();
+    let op: InstructionVariant =
+        Instruction::new(mnemonic::Jsr, addressing_mode::Absolute(0x1234)).into();
+    let mcs: Vec<Vec<Microcode>> = op.generate(&cpu).into();
+
+    assert_eq!(6, mcs.len());
+    assert!(mcs[..3].iter().all(|cycle| cycle.is_empty()));
+    assert_eq!(
+        vec![
+            gen_write_memory_microcode!(0x01ff, 0x00),
+            gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1)
+        ],
+        mcs[3]
+    );
+    assert_eq!(
+        vec![
+            gen_write_memory_microcode!(0x01fe, 0x02),
+            gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1)
+        ],
+        mcs[4]
+    );
+    assert_eq!(
+        vec![
+            gen_write_16bit_register_microcode!(WordRegisters::Pc, 0x1234),
+            gen_inc_16bit_register_microcode!(WordRegisters::Pc, 0)
+        ],
+        mcs[5]
+    );
+}
+
+#[test]
+fn should_clamp_scheduled_microcode_past_the_final_cycle() {
+    let mcs: Vec<Vec<Microcode>> = Operations::scheduled(
+        1,
+        2,
+        vec![(5, gen_flag_set_microcode!(ProgramStatusFlags::Carry, true))],
+    )
+    .into();
+
+    assert_eq!(
+        vec![
+            vec![],
+            vec![
+                gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+                gen_inc_16bit_register_microcode!(WordRegisters::Pc, 1)
+            ]
+        ],
+        mcs
+    );
+}
+
+#[test]
+fn should_report_register_and_flag_effects_of_lda() {
+    assert_eq!(
+        Some(InstructionEffects {
+            byte_registers: vec![ByteRegisters::Acc],
+            word_registers: vec![],
+            flags: vec![ProgramStatusFlags::Negative, ProgramStatusFlags::Zero],
+            reads_memory: false,
+            writes_memory: false,
+        }),
+        // LDA #$nn
+        instruction_effects(0xa9)
+    );
+}
+
+#[test]
+fn should_report_memory_write_and_no_flags_for_sta() {
+    let effects = instruction_effects(0x8d).unwrap(); // STA $nnnn
+
+    assert!(effects.writes_memory);
+    assert!(effects.flags.is_empty());
+    assert!(effects.byte_registers.is_empty());
+}
+
+#[test]
+fn should_report_no_effects_for_unimplemented_opcodes() {
+    assert_eq!(None, instruction_effects(0x02));
+}
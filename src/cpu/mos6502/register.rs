@@ -39,6 +39,85 @@ pub enum ProgramStatusFlags {
     Carry,
 }
 
+impl std::fmt::Display for WordRegisters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WordRegisters::Pc => write!(f, "PC"),
+        }
+    }
+}
+
+impl std::str::FromStr for WordRegisters {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "PC" => Ok(WordRegisters::Pc),
+            _ => Err(format!("invalid word register: {}", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for ByteRegisters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = match self {
+            ByteRegisters::Acc => "ACC",
+            ByteRegisters::X => "X",
+            ByteRegisters::Y => "Y",
+            ByteRegisters::Ps => "PS",
+            ByteRegisters::Sp => "SP",
+        };
+        write!(f, "{}", repr)
+    }
+}
+
+impl std::str::FromStr for ByteRegisters {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ACC" => Ok(ByteRegisters::Acc),
+            "X" => Ok(ByteRegisters::X),
+            "Y" => Ok(ByteRegisters::Y),
+            "PS" => Ok(ByteRegisters::Ps),
+            "SP" => Ok(ByteRegisters::Sp),
+            _ => Err(format!("invalid byte register: {}", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for ProgramStatusFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = match self {
+            ProgramStatusFlags::Negative => "N",
+            ProgramStatusFlags::Overflow => "V",
+            ProgramStatusFlags::Break => "B",
+            ProgramStatusFlags::Decimal => "D",
+            ProgramStatusFlags::Interrupt => "I",
+            ProgramStatusFlags::Zero => "Z",
+            ProgramStatusFlags::Carry => "C",
+        };
+        write!(f, "{}", repr)
+    }
+}
+
+impl std::str::FromStr for ProgramStatusFlags {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "N" => Ok(ProgramStatusFlags::Negative),
+            "V" => Ok(ProgramStatusFlags::Overflow),
+            "B" => Ok(ProgramStatusFlags::Break),
+            "D" => Ok(ProgramStatusFlags::Decimal),
+            "I" => Ok(ProgramStatusFlags::Interrupt),
+            "Z" => Ok(ProgramStatusFlags::Zero),
+            "C" => Ok(ProgramStatusFlags::Carry),
+            _ => Err(format!("invalid status flag: {}", s)),
+        }
+    }
+}
+
 /// Represets each type of general purpose register available in the mos6502.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum GpRegister {
@@ -47,6 +126,7 @@ pub enum GpRegister {
     Y,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub struct GeneralPurpose {
     inner: u8,
@@ -65,6 +145,7 @@ impl Register<u8, u8> for GeneralPurpose {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub struct ProgramCounter {
     inner: u16,
@@ -83,6 +164,7 @@ impl Register<u16, u16> for ProgramCounter {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct StackPointer {
     inner: u8,
@@ -118,6 +200,16 @@ const fn bit_is_set(value: u8, place: u8) -> bool {
     }
 }
 
+/// ProcessorStatus represents the status flags of the 6502. When packed into
+/// a byte, i.e. when pushed to the stack, the flags are laid out as:
+///
+/// ```text
+/// bit  7 6 5 4 3 2 1 0
+///      N V - B D I Z C
+/// ```
+///
+/// where bit 5 is unused.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct ProcessorStatus {
     pub carry: bool,
@@ -0,0 +1,89 @@
+use super::{
+    register::{GeneralPurpose, ProcessorStatus, ProgramCounter, StackPointer},
+    Mos6502,
+};
+use crate::address_map::Addressable;
+
+/// MemoryRegion captures the contents of a single mapped address range,
+/// starting at `start`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryRegion {
+    pub start: u16,
+    pub bytes: Vec<u8>,
+}
+
+/// CpuSnapshot captures the registers and cycle count of a Mos6502 along with
+/// the contents of each of its mapped address ranges. As the address map holds arbitrary
+/// addressables, memory is captured as its bytes rather than the addressables
+/// themselves.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CpuSnapshot {
+    pub acc: GeneralPurpose,
+    pub x: GeneralPurpose,
+    pub y: GeneralPurpose,
+    pub sp: StackPointer,
+    pub pc: ProgramCounter,
+    pub ps: ProcessorStatus,
+    pub cycles: usize,
+    pub memory: Vec<MemoryRegion>,
+}
+
+impl Mos6502 {
+    /// Captures the registers, cycle count and mapped memory of the cpu.
+    pub fn snapshot(&self) -> CpuSnapshot {
+        let memory = self
+            .address_map
+            .regions()
+            .map(|range| MemoryRegion {
+                start: *range.start(),
+                bytes: range
+                    .clone()
+                    .map(|addr| self.address_map.read(addr))
+                    .collect(),
+            })
+            .collect();
+
+        CpuSnapshot {
+            acc: self.acc,
+            x: self.x,
+            y: self.y,
+            sp: self.sp,
+            pc: self.pc,
+            ps: self.ps,
+            cycles: self.cycles,
+            memory,
+        }
+    }
+}
+
+impl<B: Addressable<u16, u8>> Mos6502<B> {
+    /// Restores the registers, cycle count and mapped memory of the cpu from a
+    /// snapshot.
+    /// Only bytes that differ from the snapshot are written, allowing
+    /// unmodified read-only memory to be restored. An error is returned if a
+    /// differing byte can't be written.
+    pub fn restore(&mut self, snapshot: &CpuSnapshot) -> Result<(), String> {
+        for region in snapshot.memory.iter() {
+            for (offset, &value) in region.bytes.iter().enumerate() {
+                let addr = region.start.wrapping_add(offset as u16);
+                if self.address_map.read(addr) != value {
+                    self.address_map.write(addr, value)?;
+                }
+            }
+        }
+
+        self.acc = snapshot.acc;
+        self.x = snapshot.x;
+        self.y = snapshot.y;
+        self.sp = snapshot.sp;
+        self.pc = snapshot.pc;
+        self.ps = snapshot.ps;
+        self.cycles = snapshot.cycles;
+        self.in_flight.clear();
+        self.result_stored = None;
+
+        Ok(())
+    }
+}
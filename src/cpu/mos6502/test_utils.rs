@@ -0,0 +1,115 @@
+use super::{register::ProcessorStatus, Mos6502, Ram};
+use crate::address_map::{AddressMap, Addressable};
+use crate::cpu::{register::Register, Cyclable};
+use std::ops::Range;
+
+/// The opcode treated as the end of a program by `run_program`.
+const BRK_OPCODE: u8 = 0x00;
+
+/// CpuView captures the registers of a cpu at the end of a program run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CpuView {
+    pub pc: u16,
+    pub acc: u8,
+    pub x: u8,
+    pub y: u8,
+    pub sp: u8,
+    pub ps: ProcessorStatus,
+    pub cycles: usize,
+}
+
+impl From<&Mos6502> for CpuView {
+    fn from(cpu: &Mos6502) -> Self {
+        Self {
+            pc: cpu.pc.read(),
+            acc: cpu.acc.read(),
+            x: cpu.x.read(),
+            y: cpu.y.read(),
+            sp: cpu.sp.read(),
+            ps: cpu.ps,
+            cycles: cpu.cycles(),
+        }
+    }
+}
+
+/// Loads a program into RAM at `origin`, running it until a BRK is fetched or
+/// `max_instructions` have been executed. The final registers are returned
+/// along with the contents of the `observe` region. RAM is mapped from
+/// 0x0200 through 0xffff, so `origin` must be no lower than 0x0200.
+pub fn run_program(
+    program: &[u8],
+    origin: u16,
+    max_instructions: usize,
+    observe: Range<u16>,
+) -> (CpuView, Vec<u8>) {
+    let mut cpu = Mos6502::default()
+        .register_address_space(0x0200..=0xffff, Ram::new(0x0200, 0xffff))
+        .unwrap()
+        .with_pc_register(super::ProgramCounter::with_value(origin));
+    for (addr, &value) in (origin..).zip(program.iter()) {
+        cpu.address_map.write(addr, value).unwrap();
+    }
+
+    let mut iter = cpu.into_iter();
+    for _ in 0..max_instructions {
+        if iter.state.address_map.read(iter.state.pc.read()) == BRK_OPCODE {
+            break;
+        }
+
+        let cycles = iter.next().map(|ops| ops.cycles()).unwrap_or(0);
+        iter.state.tick(cycles);
+    }
+
+    let cpu = Mos6502::from(iter);
+    let memory = observe.map(|addr| cpu.address_map.read(addr)).collect();
+    (CpuView::from(&cpu), memory)
+}
+
+/// Runs a program from the provided slice, treating it as a code space
+/// separate from data. Instructions are fetched from `program` indexed by the
+/// program counter, starting at 0, while loads and stores are routed to
+/// `data`, mapped from address 0x0000 upward. The program runs until the
+/// program counter leaves the slice or `max_instructions` have been executed,
+/// after which `data` holds the final contents of the data space.
+///
+/// An error is returned if `data` exceeds the 64KiB address space, an
+/// instruction can't be decoded, or an instruction stores to an address
+/// outside of `data`, such as a stack push with fewer than 0x200 bytes of
+/// data.
+pub fn run_slice(
+    program: &[u8],
+    data: &mut [u8],
+    max_instructions: usize,
+) -> Result<CpuView, String> {
+    let am = match data.len() {
+        0 => AddressMap::new(),
+        len if len > 0x10000 => {
+            return Err(format!(
+                "data space of {} bytes exceeds the 16-bit address space",
+                len
+            ))
+        }
+        len => AddressMap::new()
+            .register(
+                0x0000..=(len - 1) as u16,
+                Box::new(Ram::new(0x0000, (len - 1) as u16).load(data.to_vec())),
+            )
+            .unwrap(),
+    };
+    let mut cpu = Mos6502::with_addressmap(am);
+
+    for _ in 0..max_instructions {
+        let bytes = match program.get(usize::from(cpu.pc.read())..) {
+            Some(bytes) if !bytes.is_empty() => bytes,
+            _ => break,
+        };
+
+        let cycles = cpu.execute_bytes(bytes).map_err(|e| e.to_string())?;
+        cpu.tick(cycles);
+    }
+
+    for (addr, value) in data.iter_mut().enumerate() {
+        *value = cpu.address_map.read(addr as u16);
+    }
+    Ok(CpuView::from(&cpu))
+}
@@ -3,7 +3,12 @@ use crate::address_map::{
     Addressable,
 };
 use crate::cpu::{
-    mos6502::{register, register::GpRegister, Mos6502, Ram},
+    mos6502::{
+        register,
+        register::GpRegister,
+        test_utils::{run_program, run_slice},
+        Mos6502, Ram,
+    },
     register::Register,
     Cpu,
 };
@@ -17,10 +22,13 @@ fn generate_test_cpu_with_instructions(opcodes: Vec<u8>) -> Mos6502 {
         nop_sled[index] = val;
     }
 
+    // start from an empty stack with interrupts unmasked.
     Mos6502::default()
         .reset()
         .unwrap()
         .with_pc_register(register::ProgramCounter::with_value(start_addr))
+        .with_sp_register(register::StackPointer::default())
+        .with_ps_register(register::ProcessorStatus::default())
         .register_address_space(
             start_addr..=stop_addr,
             Rom::new(0x6000, 0x7000).load(nop_sled),
@@ -364,6 +372,50 @@ fn should_cycle_on_adc_zeropage_indexed_with_x_operation_without_overflow() {
     );
 }
 
+#[test]
+fn adc_should_add_in_binary_coded_decimal_when_decimal_set() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x69, 0x01])
+        .with_gp_register(GpRegister::Acc, register::GeneralPurpose::with_value(0x09))
+        .with_ps_register({
+            let mut ps = register::ProcessorStatus::default();
+            ps.decimal = true;
+            ps
+        });
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x10, state.acc.read());
+    assert_eq!((false, false), (state.ps.carry, state.ps.zero));
+}
+
+#[test]
+fn adc_should_carry_out_of_binary_coded_decimal_sums_over_99() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x69, 0x01])
+        .with_gp_register(GpRegister::Acc, register::GeneralPurpose::with_value(0x99))
+        .with_ps_register({
+            let mut ps = register::ProcessorStatus::default();
+            ps.decimal = true;
+            ps
+        });
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x00, state.acc.read());
+    assert_eq!((true, true), (state.ps.carry, state.ps.zero));
+
+    // the carry in is included in the decimal sum.
+    let cpu = generate_test_cpu_with_instructions(vec![0x69, 0x28])
+        .with_gp_register(GpRegister::Acc, register::GeneralPurpose::with_value(0x71))
+        .with_ps_register({
+            let mut ps = register::ProcessorStatus::default();
+            ps.decimal = true;
+            ps.carry = true;
+            ps
+        });
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x00, state.acc.read());
+    assert!(state.ps.carry);
+}
+
 #[test]
 fn should_cycle_on_and_absolute_operation() {
     let mut cpu = generate_test_cpu_with_instructions(vec![0x2d, 0xff, 0x00])
@@ -547,7 +599,7 @@ fn bcc_relative_operation_should_jump_when_zero_set() {
 
     // 3 cycles with branch penalty
     let state = cpu.run(3).unwrap();
-    assert_eq!(0x6008, state.pc.read());
+    assert_eq!(0x600a, state.pc.read());
 }
 
 #[test]
@@ -557,7 +609,7 @@ fn bcc_relative_operation_should_incur_penalty_at_page_boundary() {
 
     // 4 cycles with branch penalty
     let state = cpu.run(4).unwrap();
-    assert_eq!(0x5ff8, state.pc.read());
+    assert_eq!(0x5ffa, state.pc.read());
 }
 
 #[test]
@@ -569,6 +621,52 @@ fn bcc_relative_operation_should_not_jump_when_zero_unset() {
     assert_eq!(0x6002, state.pc.read());
 }
 
+#[test]
+fn branch_with_negative_offset_should_loop_to_itself() {
+    // BNE -2
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xd0, 0xfe]);
+    cpu.ps.zero = false;
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6000, state.pc.read());
+
+    let state = state.run(9).unwrap();
+    assert_eq!(0x6000, state.pc.read());
+}
+
+#[test]
+fn branch_should_incur_page_penalty_relative_to_the_next_instruction() {
+    let mut program = vec![0xea; 0xf2];
+    // BCC +16 from 0x60f0, crossing into the next page.
+    program[0xf0] = 0x90;
+    program[0xf1] = 0x10;
+    let mut cpu = generate_test_cpu_with_instructions(program)
+        .with_pc_register(register::ProgramCounter::with_value(0x60f0));
+    cpu.ps.carry = false;
+
+    let state = cpu.clone().run(3).unwrap();
+    assert_eq!(0x60f0, state.pc.read());
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6102, state.pc.read());
+}
+
+#[test]
+fn branch_should_not_incur_page_penalty_when_only_its_own_address_crosses() {
+    let mut program = vec![0xea; 0x104];
+    // BCC +2 from 0x60fe, landing on the page of the next instruction.
+    program[0xfe] = 0x90;
+    program[0xff] = 0x02;
+    let mut cpu = generate_test_cpu_with_instructions(program)
+        .with_pc_register(register::ProgramCounter::with_value(0x60fe));
+    cpu.ps.carry = false;
+
+    // 3 cycles with branch penalty but no page boundary penalty
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x6102, state.pc.read());
+    assert_eq!(3, state.cycles());
+}
+
 #[test]
 fn bcs_relative_operation_should_jump_when_zero_set() {
     let mut cpu = generate_test_cpu_with_instructions(vec![0xb0, 0x08]);
@@ -576,7 +674,7 @@ fn bcs_relative_operation_should_jump_when_zero_set() {
 
     // 3 cycles with branch penalty
     let state = cpu.run(3).unwrap();
-    assert_eq!(0x6008, state.pc.read());
+    assert_eq!(0x600a, state.pc.read());
 }
 
 #[test]
@@ -586,7 +684,7 @@ fn bcs_relative_operation_should_incur_penalty_at_page_boundary() {
 
     // 4 cycles with branch penalty
     let state = cpu.run(4).unwrap();
-    assert_eq!(0x5ff8, state.pc.read());
+    assert_eq!(0x5ffa, state.pc.read());
 }
 
 #[test]
@@ -605,7 +703,7 @@ fn beq_relative_operation_should_jump_when_zero_set() {
 
     // 3 cycles with branch penalty
     let state = cpu.run(3).unwrap();
-    assert_eq!(0x6008, state.pc.read());
+    assert_eq!(0x600a, state.pc.read());
 }
 
 #[test]
@@ -615,7 +713,7 @@ fn beq_relative_operation_should_incur_penalty_at_page_boundary() {
 
     // 4 cycles with branch penalty
     let state = cpu.run(4).unwrap();
-    assert_eq!(0x5ff8, state.pc.read());
+    assert_eq!(0x5ffa, state.pc.read());
 }
 
 #[test]
@@ -634,7 +732,7 @@ fn bmi_relative_operation_should_jump_when_zero_set() {
 
     // 3 cycles with branch penalty
     let state = cpu.run(3).unwrap();
-    assert_eq!(0x6008, state.pc.read());
+    assert_eq!(0x600a, state.pc.read());
 }
 
 #[test]
@@ -644,7 +742,7 @@ fn bmi_relative_operation_should_incur_penalty_at_page_boundary() {
 
     // 4 cycles with branch penalty
     let state = cpu.run(4).unwrap();
-    assert_eq!(0x5ff8, state.pc.read());
+    assert_eq!(0x5ffa, state.pc.read());
 }
 
 #[test]
@@ -686,6 +784,20 @@ fn should_cycle_on_bit_zeropage_operation() {
     );
 }
 
+#[test]
+fn bit_operation_should_take_negative_and_overflow_from_memory() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x24, 0xff])
+        .with_gp_register(GpRegister::Acc, register::GeneralPurpose::with_value(0x3f));
+    cpu.address_map.write(0x00ff, 0xc0).unwrap();
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x3f, state.acc.read());
+    assert_eq!(
+        (true, true, true),
+        (state.ps.negative, state.ps.overflow, state.ps.zero)
+    );
+}
+
 #[test]
 fn bne_relative_operation_should_jump_when_zero_set() {
     let mut cpu = generate_test_cpu_with_instructions(vec![0xd0, 0x08]);
@@ -693,7 +805,7 @@ fn bne_relative_operation_should_jump_when_zero_set() {
 
     // 3 cycles with branch penalty
     let state = cpu.run(3).unwrap();
-    assert_eq!(0x6008, state.pc.read());
+    assert_eq!(0x600a, state.pc.read());
 }
 
 #[test]
@@ -703,7 +815,7 @@ fn bne_relative_operation_should_incur_penalty_at_page_boundary() {
 
     // 4 cycles with branch penalty
     let state = cpu.run(4).unwrap();
-    assert_eq!(0x5ff8, state.pc.read());
+    assert_eq!(0x5ffa, state.pc.read());
 }
 
 #[test]
@@ -722,7 +834,7 @@ fn bpl_relative_operation_should_jump_when_zero_set() {
 
     // 3 cycles with branch penalty
     let state = cpu.run(3).unwrap();
-    assert_eq!(0x6008, state.pc.read());
+    assert_eq!(0x600a, state.pc.read());
 }
 
 #[test]
@@ -732,7 +844,7 @@ fn bpl_relative_operation_should_incur_penalty_at_page_boundary() {
 
     // 4 cycles with branch penalty
     let state = cpu.run(4).unwrap();
-    assert_eq!(0x5ff8, state.pc.read());
+    assert_eq!(0x5ffa, state.pc.read());
 }
 
 #[test]
@@ -756,10 +868,44 @@ fn should_cycle_on_brk_implied_operation() {
             0xfffe..=0xffff,
             Rom::new(0xfffe, 0xffff).load(vec![0x78, 0x56]),
         )
-        .unwrap();
+        .unwrap()
+        .with_sp_register(register::StackPointer::with_value(0xff));
     let state = cpu.run(7).unwrap();
     assert_eq!(0x5678, state.pc.read());
-    assert_eq!((true, true), (state.ps.brk, state.ps.interrupt_disable))
+    assert_eq!((true, true), (state.ps.brk, state.ps.interrupt_disable));
+
+    // return address and status, with the break flag set, are on the stack.
+    assert_eq!(0xfc, state.sp.read());
+    assert_eq!(0x60, state.address_map.read(0x01ff));
+    assert_eq!(0x02, state.address_map.read(0x01fe));
+    assert_eq!(0x30, state.address_map.read(0x01fd));
+}
+
+#[test]
+fn should_skip_the_brk_padding_byte_when_returning_with_rti() {
+    // BRK, padding byte, NOP followed by an RTI handler at 0x6010.
+    let mut program = vec![0xea; 0x11];
+    program[0] = 0x00;
+    program[1] = 0xff;
+    program[0x10] = 0x40;
+    let mut cpu = generate_test_cpu_with_instructions(program)
+        .register_address_space(
+            0xfffe..=0xffff,
+            Rom::new(0xfffe, 0xffff).load(vec![0x10, 0x60]),
+        )
+        .unwrap();
+    cpu.sp = register::StackPointer::with_value(0xff);
+
+    // BRK pushes PC + 2 with the high byte first.
+    let state = cpu.run(7).unwrap();
+    assert_eq!(0x6010, state.pc.read());
+    assert_eq!(0x60, state.address_map.read(0x01ff));
+    assert_eq!(0x02, state.address_map.read(0x01fe));
+
+    // RTI resumes past the padding byte.
+    let state = state.run(6).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0xff, state.sp.read());
 }
 
 #[test]
@@ -769,7 +915,7 @@ fn bvc_relative_operation_should_jump_when_overflow_set() {
 
     // 3 cycles with branch penalty
     let state = cpu.run(3).unwrap();
-    assert_eq!(0x6008, state.pc.read());
+    assert_eq!(0x600a, state.pc.read());
 }
 
 #[test]
@@ -779,7 +925,7 @@ fn bvc_relative_operation_should_incur_penalty_at_page_boundary() {
 
     // 4 cycles with branch penalty
     let state = cpu.run(4).unwrap();
-    assert_eq!(0x5ff8, state.pc.read());
+    assert_eq!(0x5ffa, state.pc.read());
 }
 
 #[test]
@@ -798,7 +944,7 @@ fn bvs_relative_operation_should_jump_when_overflow_set() {
 
     // 3 cycles with branch penalty
     let state = cpu.run(3).unwrap();
-    assert_eq!(0x6008, state.pc.read());
+    assert_eq!(0x600a, state.pc.read());
 }
 
 #[test]
@@ -808,7 +954,7 @@ fn bvs_relative_operation_should_incur_penalty_at_page_boundary() {
 
     // 4 cycles with branch penalty
     let state = cpu.run(4).unwrap();
-    assert_eq!(0x5ff8, state.pc.read());
+    assert_eq!(0x5ffa, state.pc.read());
 }
 
 #[test]
@@ -1360,11 +1506,10 @@ fn should_cycle_on_cpy_zeropage_operation_with_equal_operands() {
 
 #[test]
 fn should_cycle_on_dec_absolute_operation() {
-    let cpu = generate_test_cpu_with_instructions(vec![0xce, 0xff, 0x01]);
+    let (state, memory) = run_program(&[0xce, 0xff, 0x01], 0x0200, 1, 0x01ff..0x0200);
 
-    let state = cpu.run(6).unwrap();
-    assert_eq!(0x6003, state.pc.read());
-    assert_eq!(0xff, state.address_map.read(0x01ff));
+    assert_eq!((0x0203, 6), (state.pc, state.cycles));
+    assert_eq!(vec![0xff], memory);
     assert_eq!((true, false), (state.ps.negative, state.ps.zero));
 }
 
@@ -1608,6 +1753,19 @@ fn should_cycle_on_jmp_indirect_operation() {
     assert_eq!(0xeaea, state.pc.read());
 }
 
+#[test]
+fn jmp_indirect_should_not_carry_into_the_pointer_high_byte() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x6c, 0xff, 0x30])
+        .register_address_space(0x3000..=0x31ff, Ram::new(0x3000, 0x31ff))
+        .unwrap();
+    cpu.address_map.write(0x30ff, 0x34).unwrap();
+    cpu.address_map.write(0x3000, 0x12).unwrap();
+    cpu.address_map.write(0x3100, 0x56).unwrap();
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x1234, state.pc.read());
+}
+
 #[test]
 fn should_cycle_on_jsr_absolute_operation() {
     let cpu = generate_test_cpu_with_instructions(vec![0x20, 0x50, 0x60]);
@@ -1625,6 +1783,23 @@ fn should_cycle_on_jsr_absolute_operation() {
     )
 }
 
+#[test]
+fn jsr_should_wrap_the_stack_pointer_when_pushing_the_return_address() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x20, 0x50, 0x60])
+        .with_sp_register(register::StackPointer::with_value(0x00));
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6050, state.pc.read());
+    assert_eq!(0xfe, state.sp.read());
+    assert_eq!(
+        (0x02, 0x60),
+        (
+            state.address_map.read(0x01ff),
+            state.address_map.read(0x0100)
+        )
+    )
+}
+
 #[test]
 fn should_cycle_on_lda_immediate_operation() {
     let cpu = generate_test_cpu_with_instructions(vec![0xa9, 0xff, 0xa9, 0x0f]);
@@ -2049,7 +2224,18 @@ fn should_cycle_on_php_implied_operation() {
 
     let state = cpu.run(3).unwrap();
     assert_eq!(0x6001, state.pc.read());
-    assert_eq!(0x55, state.address_map.read(0x01ff));
+    assert_eq!(0x75, state.address_map.read(0x01ff));
+}
+
+#[test]
+fn php_should_push_status_with_break_and_unused_bits_set() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x08])
+        .with_ps_register(register::ProcessorStatus::with_value(0x00));
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0x30, state.address_map.read(0x01ff));
+    // the live status register is unaffected.
+    assert_eq!(0x00, state.ps.read());
 }
 
 #[test]
@@ -2074,7 +2260,18 @@ fn should_cycle_on_plp_implied_operation() {
 
     let state = cpu.run(4).unwrap();
     assert_eq!(0x6001, state.pc.read());
-    assert_eq!(0x55, state.ps.read());
+    assert_eq!(0x65, state.ps.read());
+}
+
+#[test]
+fn plp_should_not_set_the_break_flag_from_the_stack() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x28])
+        .with_sp_register(register::StackPointer::with_value(0xfe));
+    cpu.address_map.write(0x01ff, 0x10).unwrap();
+
+    let state = cpu.run(4).unwrap();
+    assert!(!state.ps.brk);
+    assert_eq!(0x20, state.ps.read());
 }
 
 #[test]
@@ -2291,10 +2488,27 @@ fn should_cycle_on_rti_implied_operation() {
 
     let state = cpu.run(6).unwrap();
     assert_eq!(0xff, state.sp.read());
-    assert_eq!(0x1235, state.pc.read());
+    assert_eq!(0x1234, state.pc.read());
     assert_eq!((false, false), (state.ps.brk, state.ps.interrupt_disable));
 }
 
+#[test]
+fn rti_should_restore_status_and_exact_return_address() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x40])
+        .with_sp_register(register::StackPointer::with_value(0xfc))
+        .with_ps_register(register::ProcessorStatus::with_value(0x20));
+
+    cpu.address_map.write(0x01ff, 0x60).unwrap();
+    cpu.address_map.write(0x01fe, 0x10).unwrap();
+    cpu.address_map.write(0x01fd, 0xdf).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x6010, state.pc.read());
+    assert_eq!(0xff, state.sp.read());
+    // break is discarded and the unused bit is always set.
+    assert_eq!(0xef, state.ps.read());
+}
+
 #[test]
 fn should_cycle_on_rts_implied_operation() {
     let mut cpu = generate_test_cpu_with_instructions(vec![0x60])
@@ -2307,6 +2521,20 @@ fn should_cycle_on_rts_implied_operation() {
     assert_eq!(0x6003, state.pc.read());
 }
 
+#[test]
+fn rts_should_return_to_the_instruction_following_jsr() {
+    // JSR $6010 with an RTS at the subroutine address.
+    let mut program = vec![0xea; 0x11];
+    program[..3].copy_from_slice(&[0x20, 0x10, 0x60]);
+    program[0x10] = 0x60;
+    let cpu = generate_test_cpu_with_instructions(program)
+        .with_sp_register(register::StackPointer::with_value(0xff));
+
+    let state = cpu.run(12).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0xff, state.sp.read());
+}
+
 #[test]
 fn should_cycle_on_sbc_absolute_operation_with_overflow() {
     let mut cpu = generate_test_cpu_with_instructions(vec![0xed, 0xff, 0x00])
@@ -2688,6 +2916,82 @@ fn should_cycle_on_sbc_zeropage_indexed_with_x_operation_without_overflow() {
     );
 }
 
+#[test]
+fn sbc_should_set_overflow_when_the_result_crosses_the_sign_boundary() {
+    let cases = [
+        // (acc, operand, result, carry, overflow)
+        (0x50, 0xb0, 0xa0, false, true),
+        (0x50, 0x30, 0x20, true, false),
+        (0xd0, 0x70, 0x60, true, true),
+        (0x00, 0x00, 0x00, true, false),
+    ];
+
+    for &(acc, operand, result, carry, overflow) in cases.iter() {
+        let cpu = generate_test_cpu_with_instructions(vec![0xe9, operand])
+            .with_gp_register(GpRegister::Acc, register::GeneralPurpose::with_value(acc))
+            .with_ps_register({
+                let mut ps = register::ProcessorStatus::default();
+                ps.carry = true;
+                ps
+            });
+
+        let state = cpu.run(2).unwrap();
+        assert_eq!(result, state.acc.read());
+        assert_eq!(
+            (carry, overflow),
+            (state.ps.carry, state.ps.overflow),
+            "{:#04x} - {:#04x}",
+            acc,
+            operand
+        );
+    }
+}
+
+#[test]
+fn sbc_should_subtract_in_binary_coded_decimal_when_decimal_set() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xe9, 0x01])
+        .with_gp_register(GpRegister::Acc, register::GeneralPurpose::with_value(0x50))
+        .with_ps_register({
+            let mut ps = register::ProcessorStatus::default();
+            ps.decimal = true;
+            ps.carry = true;
+            ps
+        });
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x49, state.acc.read());
+    assert!(state.ps.carry);
+}
+
+#[test]
+fn sbc_should_borrow_when_binary_coded_decimal_result_underflows() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xe9, 0x01])
+        .with_gp_register(GpRegister::Acc, register::GeneralPurpose::with_value(0x00))
+        .with_ps_register({
+            let mut ps = register::ProcessorStatus::default();
+            ps.decimal = true;
+            ps.carry = true;
+            ps
+        });
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x99, state.acc.read());
+    assert!(!state.ps.carry);
+
+    // an unset carry borrows an additional 1.
+    let cpu = generate_test_cpu_with_instructions(vec![0xe9, 0x19])
+        .with_gp_register(GpRegister::Acc, register::GeneralPurpose::with_value(0x40))
+        .with_ps_register({
+            let mut ps = register::ProcessorStatus::default();
+            ps.decimal = true;
+            ps
+        });
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x20, state.acc.read());
+    assert!(state.ps.carry);
+}
+
 #[test]
 fn should_cycle_on_sec_implied_operation() {
     let mut cpu = generate_test_cpu_with_instructions(vec![0x38]);
@@ -2961,3 +3265,1179 @@ fn should_cycle_on_tya_implied_operation() {
     assert_eq!(0xff, state.y.read());
     assert_eq!((state.ps.negative, state.ps.zero), (true, false));
 }
+
+// Fast mode
+
+#[test]
+fn should_not_incur_page_boundary_penalties_in_fast_mode() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x7d, 0xfa, 0x00])
+        .with_gp_register(GpRegister::Acc, register::GeneralPurpose::with_value(0x10))
+        .with_gp_register(GpRegister::X, register::GeneralPurpose::with_value(0x10))
+        .with_fast_mode(true);
+    cpu.address_map.write(0x010a, 0x50).unwrap();
+
+    // 4 base cycles with no page boundary penalty
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(0x60, state.acc.read());
+}
+
+#[test]
+fn should_not_incur_branch_penalties_in_fast_mode() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x90, 0xf8]).with_fast_mode(true);
+    cpu.ps.carry = false;
+
+    // 2 base cycles with no branch or page boundary penalty
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x5ffa, state.pc.read());
+}
+
+// Stack guard
+
+#[test]
+fn should_invoke_stack_guard_handler_when_pha_wraps_the_stack_pointer() {
+    use std::{cell::RefCell, rc::Rc};
+
+    let wraps = Rc::new(RefCell::new(Vec::new()));
+    let handler_wraps = wraps.clone();
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x48])
+        .with_sp_register(register::StackPointer::with_value(0x00));
+    cpu.set_stack_guard(true);
+    cpu.set_stack_guard_handler(move |pc, sp_before, sp_after| {
+        handler_wraps.borrow_mut().push((pc, sp_before, sp_after))
+    });
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0xff, state.sp.read());
+    assert_eq!(vec![(0x6000, 0x00, 0xff)], *wraps.borrow());
+}
+
+#[test]
+fn should_not_invoke_stack_guard_handler_when_disabled() {
+    use std::{cell::RefCell, rc::Rc};
+
+    let wraps = Rc::new(RefCell::new(Vec::<(u16, u8, u8)>::new()));
+    let handler_wraps = wraps.clone();
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x48])
+        .with_sp_register(register::StackPointer::with_value(0x00));
+    cpu.set_stack_guard_handler(move |pc, sp_before, sp_after| {
+        handler_wraps.borrow_mut().push((pc, sp_before, sp_after))
+    });
+
+    let state = cpu.run(3).unwrap();
+    assert_eq!(0xff, state.sp.read());
+    assert!(wraps.borrow().is_empty());
+}
+
+// Write watch
+
+#[test]
+fn should_invoke_write_watch_once_per_write_when_run() {
+    use std::{cell::RefCell, rc::Rc};
+
+    let writes = Rc::new(RefCell::new(Vec::new()));
+    let watched = writes.clone();
+    // LDA #$42, STA $10
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xa9, 0x42, 0x85, 0x10]);
+    cpu.address_map.watch(0x0010..=0x0010, move |addr, value| {
+        watched.borrow_mut().push((addr, value))
+    });
+
+    let state = cpu.run(5).unwrap();
+    assert_eq!(0x42, state.address_map.read(0x0010));
+    assert_eq!(vec![(0x0010, 0x42)], *writes.borrow());
+}
+
+// Cycle accurate
+
+#[test]
+fn should_write_unmodified_value_before_result_on_inc_absolute_when_cycle_accurate() {
+    use std::{cell::RefCell, rc::Rc};
+
+    /// Records every value written to it and returns the last write on reads.
+    #[derive(Clone)]
+    struct BusWatch {
+        value: u8,
+        writes: Rc<RefCell<Vec<u8>>>,
+    }
+
+    impl Addressable<u16, u8> for BusWatch {
+        fn read(&self, _: u16) -> u8 {
+            self.value
+        }
+
+        fn write(&mut self, _: u16, value: u8) -> Result<u8, String> {
+            self.value = value;
+            self.writes.borrow_mut().push(value);
+            Ok(value)
+        }
+    }
+
+    let writes = Rc::new(RefCell::new(Vec::new()));
+    let cpu = generate_test_cpu_with_instructions(vec![0xee, 0x00, 0x80])
+        .register_address_space(
+            0x8000..=0x8000,
+            BusWatch {
+                value: 0x41,
+                writes: writes.clone(),
+            },
+        )
+        .unwrap()
+        .with_cycle_accurate(true);
+
+    cpu.into_iter().next();
+    assert_eq!(vec![0x41, 0x42], *writes.borrow());
+}
+
+#[test]
+fn should_write_unmodified_value_a_cycle_before_asl_zeropage_result_when_cycle_accurate() {
+    use std::{cell::RefCell, rc::Rc};
+
+    let writes = Rc::new(RefCell::new(Vec::new()));
+    let watched = writes.clone();
+    // ASL $10
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x06, 0x10]).with_cycle_accurate(true);
+    cpu.address_map.write(0x0010, 0x41).unwrap();
+    cpu.address_map.watch(0x0010..=0x0010, move |_, value| {
+        watched.borrow_mut().push(value)
+    });
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(vec![0x41], *writes.borrow());
+
+    state.run(1).unwrap();
+    assert_eq!(vec![0x41, 0x82], *writes.borrow());
+}
+
+// Split instructions
+
+#[test]
+fn should_complete_a_jsr_split_across_runs_without_repeating_its_pushes() {
+    // JSR $6010
+    let cpu = generate_test_cpu_with_instructions(vec![0x20, 0x10, 0x60]);
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0xfe, state.sp.read());
+    assert_eq!(0x60, state.address_map.read(0x01ff));
+
+    let state = state.run(2).unwrap();
+    assert_eq!(0x6010, state.pc.read());
+    assert_eq!(0xfd, state.sp.read());
+    assert_eq!(0x60, state.address_map.read(0x01ff));
+    assert_eq!(0x02, state.address_map.read(0x01fe));
+}
+
+// Instruction fetch
+
+#[test]
+fn should_not_read_past_an_implied_instruction_at_the_last_valid_address() {
+    use std::{cell::RefCell, rc::Rc};
+
+    /// Records the address of every read made against it.
+    #[derive(Clone)]
+    struct ReadWatch {
+        reads: Rc<RefCell<Vec<u16>>>,
+    }
+
+    impl Addressable<u16, u8> for ReadWatch {
+        fn read(&self, addr: u16) -> u8 {
+            self.reads.borrow_mut().push(addr);
+            // nop
+            0xea
+        }
+
+        fn write(&mut self, _: u16, value: u8) -> Result<u8, String> {
+            Ok(value)
+        }
+    }
+
+    let reads = Rc::new(RefCell::new(Vec::new()));
+    let cpu = Mos6502::default()
+        .register_address_space(
+            0xff00..=0xffff,
+            ReadWatch {
+                reads: reads.clone(),
+            },
+        )
+        .unwrap()
+        .with_pc_register(register::ProgramCounter::with_value(0xffff));
+
+    cpu.into_iter().next();
+    assert_eq!(vec![0xffff], *reads.borrow());
+}
+
+// Executable regions
+
+#[test]
+fn should_invoke_escape_handler_when_jmp_targets_a_non_executable_address() {
+    use std::{cell::RefCell, rc::Rc};
+
+    let escapes = Rc::new(RefCell::new(Vec::new()));
+    let handler_escapes = escapes.clone();
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x4c, 0x00, 0x80]);
+    cpu.set_executable_regions(vec![0x6000..0x7001]);
+    cpu.set_executable_region_escape_handler(move |pc| handler_escapes.borrow_mut().push(pc));
+
+    // 3 cycles to jump and 1 to fetch from the jump target.
+    let state = cpu.run(4).unwrap();
+    assert_eq!(vec![0x8000], *escapes.borrow());
+    assert_eq!(0x8000, state.pc.read());
+}
+
+// Interrupts
+
+#[test]
+fn should_account_for_seven_cycles_when_servicing_an_irq() {
+    use std::{cell::Cell, rc::Rc};
+
+    let ticks = Rc::new(Cell::new(0));
+    let callback_ticks = ticks.clone();
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xea]);
+    cpu.set_cycle_callback(move |_| callback_ticks.set(callback_ticks.get() + 1));
+
+    let state = cpu.irq().unwrap();
+    assert_eq!(7, state.cycles());
+    assert_eq!(7, ticks.get());
+    assert!(state.ps.interrupt_disable);
+    assert_eq!(0xfc, state.sp.read());
+    assert_eq!(0x60, state.address_map.read(0x01ff));
+    assert_eq!(0x00, state.address_map.read(0x01fe));
+}
+
+#[test]
+fn should_ignore_an_irq_while_interrupts_are_disabled() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xea]);
+    cpu.ps.interrupt_disable = true;
+
+    let state = cpu.irq().unwrap();
+    assert_eq!(0, state.cycles());
+    assert_eq!(0x6000, state.pc.read());
+}
+
+#[test]
+fn should_vector_an_irq_and_return_to_the_interrupted_address_with_rti() {
+    // NOP with an RTI handler at 0x6010.
+    let mut program = vec![0xea; 0x11];
+    program[0x10] = 0x40;
+    let mut cpu = generate_test_cpu_with_instructions(program)
+        .register_address_space(
+            0xfffe..=0xffff,
+            Rom::new(0xfffe, 0xffff).load(vec![0x10, 0x60]),
+        )
+        .unwrap()
+        .with_ps_register(register::ProcessorStatus::with_value(0x21));
+    cpu.sp = register::StackPointer::with_value(0xff);
+
+    let state = cpu.irq().unwrap();
+    assert_eq!(0x6010, state.pc.read());
+    // the pushed status has the break flag clear.
+    assert_eq!(0x21, state.address_map.read(0x01fd));
+
+    let state = state.run(6).unwrap();
+    assert_eq!(0x6000, state.pc.read());
+    assert_eq!(0xff, state.sp.read());
+    assert_eq!(0x21, state.ps.read());
+}
+
+#[test]
+fn should_service_an_nmi_while_interrupts_are_disabled() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xea])
+        .register_address_space(
+            0xfffa..=0xfffb,
+            Rom::new(0xfffa, 0xfffb).load(vec![0x34, 0x12]),
+        )
+        .unwrap()
+        .with_ps_register(register::ProcessorStatus::with_value(0x34));
+    cpu.sp = register::StackPointer::with_value(0xff);
+
+    let state = cpu.nmi().unwrap();
+    assert_eq!(0x1234, state.pc.read());
+    assert_eq!(7, state.cycles());
+    assert_eq!(0xfc, state.sp.read());
+    assert_eq!(0x60, state.address_map.read(0x01ff));
+    assert_eq!(0x00, state.address_map.read(0x01fe));
+    // the pushed status has the break flag clear.
+    assert_eq!(0x24, state.address_map.read(0x01fd));
+}
+
+#[test]
+fn should_complete_an_in_flight_instruction_before_servicing_an_nmi() {
+    // LDA #$42
+    let cpu = generate_test_cpu_with_instructions(vec![0xa9, 0x42])
+        .register_address_space(
+            0xfffa..=0xfffb,
+            Rom::new(0xfffa, 0xfffb).load(vec![0x10, 0x60]),
+        )
+        .unwrap();
+
+    // stop partway through the load.
+    let state = cpu.run(1).unwrap();
+    assert_eq!(0x00, state.acc.read());
+
+    let state = state.nmi().unwrap();
+    assert_eq!(0x42, state.acc.read());
+    assert_eq!(0x6010, state.pc.read());
+    assert_eq!(9, state.cycles());
+    // the return address is that of the instruction following the load.
+    assert_eq!(0x60, state.address_map.read(0x01ff));
+    assert_eq!(0x02, state.address_map.read(0x01fe));
+
+    // the nop handler begins at the vector rather than replaying the load.
+    let state = state.run(2).unwrap();
+    assert_eq!(0x6011, state.pc.read());
+}
+
+#[test]
+fn should_count_page_penalties_in_the_running_cycle_total() {
+    // ADC #$01, LDA $60ff,X
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x69, 0x01, 0xbd, 0xff, 0x60])
+        .with_gp_register(GpRegister::X, register::GeneralPurpose::with_value(0x01));
+
+    cpu.step().unwrap();
+    cpu.step().unwrap();
+    assert_eq!(7, cpu.cycles());
+
+    cpu.reset_cycle_counter();
+    assert_eq!(0, cpu.cycles());
+}
+
+#[test]
+fn should_accumulate_a_cycle_for_each_cycle_run() {
+    let cpu = generate_test_cpu_with_instructions(vec![0xea, 0xea]);
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(4, state.cycles());
+}
+
+// State diff
+
+#[test]
+fn should_report_only_the_program_counter_after_running_an_extra_instruction() {
+    use crate::cpu::mos6502::{diff_state, register::Registers, StateDiff};
+
+    let cpu = generate_test_cpu_with_instructions(vec![0xea, 0xea]);
+    let a = cpu.clone().run(2).unwrap();
+    let b = cpu.run(4).unwrap();
+
+    assert_eq!(
+        vec![StateDiff::Register {
+            register: Registers::Pc,
+            a: 0x6001,
+            b: 0x6002
+        }],
+        diff_state(&a, &b, 0x0000..=0x01ff)
+    );
+}
+
+#[test]
+fn should_report_differing_flags_and_memory() {
+    use crate::cpu::mos6502::{diff_state, register::ProgramStatusFlags, StateDiff};
+
+    let a = generate_test_cpu_with_instructions(vec![]);
+    let mut b = a.clone();
+    b.ps.carry = true;
+    b.address_map.write(0x01fe, 0xff).unwrap();
+
+    assert_eq!(
+        vec![
+            StateDiff::Flag {
+                flag: ProgramStatusFlags::Carry,
+                a: false,
+                b: true
+            },
+            StateDiff::Memory {
+                address: 0x01fe,
+                a: 0x00,
+                b: 0xff
+            },
+        ],
+        diff_state(&a, &b, 0x01f0..=0x01ff)
+    );
+}
+
+// Execute bytes
+
+#[test]
+fn should_execute_an_instruction_from_provided_bytes() {
+    let mut cpu = Mos6502::default().with_pc_register(register::ProgramCounter::with_value(0x6000));
+
+    assert_eq!(Ok(2), cpu.execute_bytes(&[0xa9, 0x55]));
+    assert_eq!(0x55, cpu.acc.read());
+    assert_eq!(0x6002, cpu.pc.read());
+}
+
+#[test]
+fn should_fail_to_execute_truncated_instruction_bytes() {
+    use crate::cpu::mos6502::StepError;
+
+    let mut cpu = Mos6502::default();
+
+    assert_eq!(
+        Err(StepError::Decode {
+            opcode: 0xa9,
+            pc: 0x0000
+        }),
+        cpu.execute_bytes(&[0xa9])
+    );
+    assert_eq!(0x0000, cpu.pc.read());
+}
+
+// Flat cpu
+
+#[test]
+fn should_run_a_program_on_a_flat_cpu_and_clone_independently() {
+    use crate::address_map::memory::FlatMemory;
+    use crate::cpu::mos6502::FlatCpu;
+
+    // LDA #$42, STA $0200 with the reset vector pointed at 0x8000.
+    let memory = FlatMemory::new()
+        .load(0x8000, &[0xa9, 0x42, 0x8d, 0x00, 0x02])
+        .and_then(|memory| memory.load(0xfffc, &[0x00, 0x80]))
+        .unwrap();
+    let flat = FlatCpu::with_memory(memory).reset().unwrap();
+    let snapshot = flat.clone();
+
+    let state = flat.run(6).unwrap();
+    assert_eq!(0x8005, state.pc.read());
+    assert_eq!(0x42, state.acc.read());
+    assert_eq!(0x42, state.address_map.read(0x0200));
+
+    assert_eq!(0x8000, snapshot.pc.read());
+    assert_eq!(0x00, snapshot.address_map.read(0x0200));
+}
+
+#[test]
+fn should_step_a_flat_cpu_through_a_cycle_budget() {
+    use crate::address_map::memory::FlatMemory;
+    use crate::cpu::mos6502::FlatCpu;
+
+    // LDA #$42, STA $0200
+    let memory = FlatMemory::new()
+        .load(0x8000, &[0xa9, 0x42, 0x8d, 0x00, 0x02])
+        .unwrap();
+    let mut flat =
+        FlatCpu::with_memory(memory).with_pc_register(register::ProgramCounter::with_value(0x8000));
+
+    assert_eq!(Ok(2), flat.step());
+    assert_eq!(Ok(4), flat.run_for(1));
+    assert_eq!(0x8005, flat.pc.read());
+    assert_eq!(0x42, flat.address_map.read(0x0200));
+}
+
+#[test]
+fn should_reject_flat_memory_loads_past_the_end_of_the_address_space() {
+    use crate::address_map::memory::FlatMemory;
+
+    assert!(FlatMemory::new().load(0xffff, &[0xea, 0xea]).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn should_round_trip_a_flat_cpu_through_serde() {
+    use crate::address_map::memory::FlatMemory;
+    use crate::cpu::mos6502::FlatCpu;
+
+    let memory = FlatMemory::new().load(0x0200, &[0x42]).unwrap();
+    let flat = FlatCpu::with_memory(memory)
+        .with_gp_register(GpRegister::X, register::GeneralPurpose::with_value(0x05));
+
+    let serialized = serde_json::to_string(&flat).unwrap();
+    let deserialized: FlatCpu = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(flat.snapshot(), deserialized.snapshot());
+    assert_eq!(0x42, deserialized.address_map.read(0x0200));
+}
+
+// Program slices
+
+#[test]
+fn should_run_a_program_slice_against_a_separate_data_space() {
+    // LDA #$05, CLC, ADC #$03, STA $10
+    let program = [0xa9, 0x05, 0x18, 0x69, 0x03, 0x85, 0x10];
+    let mut data = [0u8; 0x20];
+
+    let state = run_slice(&program, &mut data, 100).unwrap();
+    assert_eq!(0x08, data[0x10]);
+    assert_eq!(0x08, state.acc);
+    assert_eq!(0x0007, state.pc);
+}
+
+#[test]
+fn should_stop_a_program_slice_after_the_instruction_limit() {
+    // JMP $0000
+    let program = [0x4c, 0x00, 0x00];
+
+    let state = run_slice(&program, &mut [], 10).unwrap();
+    assert_eq!(0x0000, state.pc);
+    assert_eq!(30, state.cycles);
+}
+
+#[test]
+fn should_return_an_error_when_a_program_slice_fails_to_decode() {
+    // LDA with a missing operand.
+    let program = [0xa9];
+
+    assert!(run_slice(&program, &mut [], 10).is_err());
+}
+
+#[test]
+fn should_reject_a_program_slice_data_space_larger_than_the_address_space() {
+    let mut data = vec![0u8; 0x10001];
+
+    assert_eq!(
+        Err("data space of 65537 bytes exceeds the 16-bit address space".to_string()),
+        run_slice(&[], &mut data, 10)
+    );
+}
+
+#[test]
+fn should_return_an_error_when_a_program_slice_stores_outside_of_data() {
+    // PHA, pushing to 0x01ff beyond a 16 byte data space.
+    let program = [0x48];
+
+    assert_eq!(
+        Err("write to 0x01ff failed: address space 511 unallocated".to_string()),
+        run_slice(&program, &mut [0u8; 0x10], 10)
+    );
+}
+
+#[test]
+fn should_run_a_program_slice_over_a_full_address_space_of_data() {
+    // LDA #$42, STA $ffff
+    let program = [0xa9, 0x42, 0x8d, 0xff, 0xff];
+    let mut data = vec![0u8; 0x10000];
+
+    let state = run_slice(&program, &mut data, 10).unwrap();
+    assert_eq!(0x0005, state.pc);
+    assert_eq!(0x42, data[0xffff]);
+}
+
+// Write protection
+
+#[test]
+fn should_fault_on_a_store_into_protected_memory() {
+    use crate::address_map::memory::ProtectedMemory;
+    use crate::cpu::mos6502::{StepError, StopReason};
+
+    // LDA #$42, STA $8010, STA $8090
+    let cpu =
+        generate_test_cpu_with_instructions(vec![0xa9, 0x42, 0x8d, 0x10, 0x80, 0x8d, 0x90, 0x80])
+            .register_address_space(
+                0x8000..=0x80ff,
+                ProtectedMemory::new(0x8000, 0x100, vec![0x00..0x80]),
+            )
+            .unwrap();
+    let expected = StepError::Write {
+        addr: 0x8090,
+        reason: "memory offset 0x0090 is write-protected".to_string(),
+    };
+
+    let mut stepped = cpu.clone();
+    assert_eq!(Ok(2), stepped.step());
+    assert_eq!(Ok(4), stepped.step());
+    assert_eq!(0x42, stepped.address_map.read(0x8010));
+    assert_eq!(Err(expected.clone()), stepped.step());
+    assert_eq!(0x00, stepped.address_map.read(0x8090));
+
+    let state = cpu.run(100).unwrap();
+    assert_eq!(Some(StopReason::Fault(expected)), state.stop_reason());
+}
+
+// Illegal opcodes
+
+#[test]
+fn should_step_over_absolute_operand_of_illegal_nop_when_enabled() {
+    let cpu =
+        generate_test_cpu_with_instructions(vec![0x0c, 0xff, 0x01]).with_illegal_opcodes(true);
+
+    let state = cpu.run(4).unwrap();
+    assert_eq!(0x6003, state.pc.read());
+}
+
+#[test]
+fn should_pay_page_penalty_on_absolute_indexed_illegal_nop() {
+    let cpu = generate_test_cpu_with_instructions(vec![0x1c, 0xff, 0x01])
+        .with_gp_register(GpRegister::X, register::GeneralPurpose::with_value(0x01))
+        .with_illegal_opcodes(true);
+
+    let mops = cpu.into_iter().next().unwrap();
+    assert_eq!(5, crate::cpu::Cyclable::cycles(&mops));
+}
+
+// Disassembly
+
+#[test]
+fn should_disassemble_a_window_of_instructions_around_the_pc() {
+    // LDA #$55, STA $0200, NOP, INX
+    let cpu = generate_test_cpu_with_instructions(vec![0xa9, 0x55, 0x8d, 0x00, 0x02, 0xea, 0xe8])
+        .with_pc_register(register::ProgramCounter::with_value(0x6005));
+
+    assert_eq!(
+        vec![
+            (0x6000, "LDA #$55".to_string(), false),
+            (0x6002, "STA $0200".to_string(), false),
+            (0x6005, "NOP".to_string(), true),
+            (0x6006, "INX".to_string(), false),
+            (0x6007, "NOP".to_string(), false),
+        ],
+        cpu.disassemble_around_pc(2, 2)
+    );
+}
+
+#[test]
+fn should_disassemble_a_stream_of_instructions_with_their_offsets() {
+    use crate::cpu::mos6502::disassemble;
+
+    // LDA #$ff, STA $0100, BNE $fb, LDA $ff,X, JMP ($1000)
+    let bytes = [
+        0xa9, 0xff, 0x8d, 0x00, 0x01, 0xd0, 0xfb, 0xb5, 0xff, 0x6c, 0x00, 0x10,
+    ];
+
+    assert_eq!(
+        vec![
+            (0x00, "LDA #$ff".to_string()),
+            (0x02, "STA $0100".to_string()),
+            (0x05, "BNE $fb".to_string()),
+            (0x07, "LDA $ff,X".to_string()),
+            (0x09, "JMP ($1000)".to_string()),
+        ],
+        disassemble(&bytes)
+    );
+}
+
+#[test]
+fn should_disassemble_trailing_partial_instructions_as_bytes() {
+    use crate::cpu::mos6502::disassemble;
+
+    // INX followed by a truncated STA $0100.
+    assert_eq!(
+        vec![
+            (0x00, "INX".to_string()),
+            (0x01, ".byte $8d".to_string()),
+            (0x02, ".byte $00".to_string()),
+        ],
+        disassemble(&[0xe8, 0x8d, 0x00])
+    );
+}
+
+#[test]
+fn should_only_disassemble_bytes_within_the_16_bit_address_space() {
+    use crate::cpu::mos6502::disassemble;
+
+    let insts = disassemble(&vec![0xea; 0x10001]);
+    assert_eq!(0x10000, insts.len());
+    assert_eq!(Some(&(0xffff, "NOP".to_string())), insts.last());
+}
+
+#[test]
+fn should_disassemble_the_instruction_at_the_head_of_the_bytes() {
+    use crate::cpu::mos6502::disassemble_instruction;
+
+    assert_eq!(
+        (3, "STA $0100".to_string()),
+        disassemble_instruction(&[0x8d, 0x00, 0x01])
+    );
+    assert_eq!(
+        (1, ".byte $02".to_string()),
+        disassemble_instruction(&[0x02])
+    );
+}
+
+#[test]
+fn should_render_instructions_in_canonical_assembly_syntax() {
+    use crate::cpu::mos6502::Assembly;
+    use isa_mos6502::{addressing_mode, mnemonic, Instruction, InstructionVariant};
+
+    assert_eq!("LDA", Assembly(mnemonic::Lda).to_string());
+    assert_eq!("STA", Assembly(mnemonic::Sta).to_string());
+    assert_eq!("A", Assembly(addressing_mode::Accumulator).to_string());
+    assert_eq!(
+        "($20,X)",
+        Assembly(addressing_mode::XIndexedIndirect(0x20)).to_string()
+    );
+    assert_eq!(
+        "$1000,Y",
+        Assembly(addressing_mode::AbsoluteIndexedWithY(0x1000)).to_string()
+    );
+
+    let cases: Vec<(InstructionVariant, &str)> = vec![
+        (
+            Instruction::new(mnemonic::Lda, addressing_mode::Immediate(0x10)).into(),
+            "LDA #$10",
+        ),
+        (
+            Instruction::new(mnemonic::Sta, addressing_mode::ZeroPage(0xff)).into(),
+            "STA $ff",
+        ),
+        (
+            Instruction::new(mnemonic::Sta, addressing_mode::AbsoluteIndexedWithX(0x1000)).into(),
+            "STA $1000,X",
+        ),
+        (
+            Instruction::new(mnemonic::Lda, addressing_mode::IndirectYIndexed(0x20)).into(),
+            "LDA ($20),Y",
+        ),
+        (
+            Instruction::new(mnemonic::Jmp, addressing_mode::Indirect(0xfffc)).into(),
+            "JMP ($fffc)",
+        ),
+        (
+            Instruction::new(mnemonic::Bne, addressing_mode::Relative(-5)).into(),
+            "BNE $fb",
+        ),
+        (
+            Instruction::new(mnemonic::Asl, addressing_mode::Accumulator).into(),
+            "ASL A",
+        ),
+        (
+            Instruction::new(mnemonic::Inx, addressing_mode::Implied).into(),
+            "INX",
+        ),
+    ];
+
+    for (inst, expected) in cases {
+        assert_eq!(expected, Assembly(inst).to_string());
+    }
+
+    assert_eq!(
+        "LDX $80,Y",
+        Assembly(Instruction::new(
+            mnemonic::Ldx,
+            addressing_mode::ZeroPageIndexedWithY(0x80)
+        ))
+        .to_string()
+    );
+}
+
+// Assembly
+
+#[test]
+fn should_round_trip_assembled_source_through_the_disassembler() {
+    use crate::cpu::mos6502::{assemble, disassemble};
+
+    let source = [
+        "LDA #$ff",
+        "STA $0100",
+        "STA $10,X",
+        "LDX $1000,Y",
+        "LDA ($20,X)",
+        "LDA ($20),Y",
+        "JMP ($fffc)",
+        "ASL A",
+        "BNE $fb",
+        "INX",
+    ];
+
+    let bytes = assemble(&source.join("\n")).unwrap();
+    let disassembled: Vec<String> = disassemble(&bytes)
+        .into_iter()
+        .map(|(_, asm)| asm)
+        .collect();
+
+    assert_eq!(source.to_vec(), disassembled);
+}
+
+#[test]
+fn should_reassemble_every_decodable_opcode_to_itself() {
+    use crate::cpu::mos6502::{assemble, disassemble};
+
+    for opcode in 0..=0xffu8 {
+        let bytes = [opcode, 0x12, 0x34];
+        let (_, asm) = disassemble(&bytes).into_iter().next().unwrap();
+        if asm.starts_with(".byte") {
+            continue;
+        }
+
+        let reassembled = assemble(&asm).unwrap();
+        assert_eq!(&bytes[..reassembled.len()], &reassembled[..], "{}", asm);
+    }
+}
+
+#[test]
+fn should_resolve_labels_to_absolute_and_relative_addresses() {
+    use crate::cpu::mos6502::assemble;
+
+    let source = "
+        .org $6000
+        start:
+            LDX #$05
+        loop: DEX       ; count down to zero
+            BNE loop
+            BEQ done
+            JMP start
+        done:
+            RTS
+    ";
+
+    assert_eq!(
+        Ok(vec![
+            0xa2, 0x05, // LDX #$05
+            0xca, // DEX
+            0xd0, 0xfd, // BNE loop
+            0xf0, 0x03, // BEQ done
+            0x4c, 0x00, 0x60, // JMP start
+            0x60, // RTS
+        ]),
+        assemble(source)
+    );
+}
+
+#[test]
+fn should_reject_invalid_assembly_with_the_offending_line() {
+    use crate::cpu::mos6502::assemble;
+
+    assert_eq!(
+        Err("line 2: invalid instruction STX $1000,X".to_string()),
+        assemble("NOP\nSTX $1000,X")
+    );
+    assert_eq!(
+        Err("line 1: undefined label missing".to_string()),
+        assemble("JMP missing")
+    );
+    assert_eq!(
+        Err("line 1: immediate value $100 exceeds 8 bits".to_string()),
+        assemble("LDA #$100")
+    );
+}
+
+// Snapshots
+
+/// Generates a cpu with the instructions loaded into a rom sized to exactly
+/// fill its registered range, as snapshots read every mapped address.
+fn generate_snapshot_test_cpu(opcodes: Vec<u8>) -> Mos6502 {
+    let mut program = vec![0xea; 0x1000];
+    program[..opcodes.len()].copy_from_slice(&opcodes);
+
+    Mos6502::default()
+        .register_address_space(0x6000..=0x6fff, Rom::new(0x6000, 0x6fff).load(program))
+        .unwrap()
+        .with_pc_register(register::ProgramCounter::with_value(0x6000))
+}
+
+#[test]
+fn should_restore_the_full_cpu_state_from_a_snapshot() {
+    // LDA #$55, STA $10, INX, SEC
+    let mut cpu = generate_snapshot_test_cpu(vec![0xa9, 0x55, 0x85, 0x10, 0xe8, 0x38]);
+    let snapshot = cpu.snapshot();
+    let (acc, x, y, sp, pc, ps) = (cpu.acc, cpu.x, cpu.y, cpu.sp, cpu.pc, cpu.ps);
+    let cycles = cpu.cycles();
+
+    for _ in 0..4 {
+        cpu.step().unwrap();
+    }
+    assert_eq!(0x55, cpu.address_map.read(0x10));
+    assert_ne!(cycles, cpu.cycles());
+
+    cpu.restore(&snapshot.clone()).unwrap();
+
+    assert_eq!(snapshot, cpu.snapshot());
+    assert_eq!(
+        (acc, x, y, sp, pc, ps),
+        (cpu.acc, cpu.x, cpu.y, cpu.sp, cpu.pc, cpu.ps)
+    );
+    assert_eq!(cycles, cpu.cycles());
+    assert_eq!(0x00, cpu.address_map.read(0x10));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn should_round_trip_a_serialized_snapshot() {
+    use crate::cpu::mos6502::CpuSnapshot;
+
+    let mut cpu = generate_snapshot_test_cpu(vec![0xa9, 0x55, 0x85, 0x10, 0xe8]);
+    let snapshot = cpu.snapshot();
+    let serialized = serde_json::to_string(&snapshot).unwrap();
+
+    // LDA #$55, STA $10, INX
+    cpu.step().unwrap();
+    cpu.step().unwrap();
+    cpu.step().unwrap();
+    assert_ne!(snapshot, cpu.snapshot());
+
+    let deserialized: CpuSnapshot = serde_json::from_str(&serialized).unwrap();
+    cpu.restore(&deserialized).unwrap();
+
+    assert_eq!(snapshot, cpu.snapshot());
+    assert_eq!(0x00, cpu.address_map.read(0x10));
+}
+
+// Tracing
+
+#[test]
+fn should_trace_each_instruction_in_the_nestest_log_format() {
+    use std::{cell::RefCell, rc::Rc};
+
+    // LDA #$55, STA $0180
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xa9, 0x55, 0x8d, 0x80, 0x01]);
+    let lines = Rc::new(RefCell::new(Vec::new()));
+    let trace = lines.clone();
+    cpu.set_trace_callback(move |line| trace.borrow_mut().push(line.to_string()));
+
+    cpu.step().unwrap();
+    cpu.step().unwrap();
+
+    assert_eq!(
+        vec![
+            "6000  A9 55     LDA #$55                        A:00 X:00 Y:00 P:20 SP:FF CYC:0",
+            "6002  8D 80 01  STA $0180                       A:55 X:00 Y:00 P:20 SP:FF CYC:2",
+        ],
+        *lines.borrow()
+    );
+}
+
+#[test]
+fn should_trace_operands_in_uppercase_when_run() {
+    use std::{cell::RefCell, rc::Rc};
+
+    // LDA #$ff, STA $01ab,X
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xa9, 0xff, 0x9d, 0xab, 0x01]);
+    let lines = Rc::new(RefCell::new(Vec::new()));
+    let trace = lines.clone();
+    cpu.set_trace_callback(move |line| trace.borrow_mut().push(line.to_string()));
+
+    cpu.run(7).unwrap();
+
+    assert_eq!(
+        vec![
+            "6000  A9 FF     LDA #$FF                        A:00 X:00 Y:00 P:20 SP:FF CYC:0",
+            "6002  9D AB 01  STA $01AB,X                     A:FF X:00 Y:00 P:A0 SP:FF CYC:2",
+        ],
+        *lines.borrow()
+    );
+}
+
+// Processor status
+
+#[test]
+fn should_pack_each_status_flag_into_its_canonical_bit() {
+    use crate::cpu::{mos6502::microcode, mos6502::register::ProgramStatusFlags, ExecuteMut};
+
+    let cases = [
+        (ProgramStatusFlags::Carry, 0),
+        (ProgramStatusFlags::Zero, 1),
+        (ProgramStatusFlags::Interrupt, 2),
+        (ProgramStatusFlags::Decimal, 3),
+        (ProgramStatusFlags::Break, 4),
+        (ProgramStatusFlags::Overflow, 6),
+        (ProgramStatusFlags::Negative, 7),
+    ];
+
+    for &(flag, bit) in cases.iter() {
+        let mut cpu = Mos6502::default().with_ps_register(register::ProcessorStatus::with_value(0));
+        cpu.execute_mut(&microcode::Microcode::SetProgramStatusFlagState(
+            microcode::SetProgramStatusFlagState::new(flag, true),
+        ));
+
+        assert_eq!(1u8 << bit, cpu.ps.read(), "{:?}", flag);
+    }
+
+    // the unused bit is represented at bit 5.
+    assert_eq!(0x20, register::ProcessorStatus::default().read() & 0x20);
+}
+
+// Traps
+
+#[test]
+fn should_stop_run_on_configured_trap_address() {
+    use crate::cpu::mos6502::StopReason;
+
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x4c, 0x10, 0x60]);
+    cpu.set_success_trap(0x6010);
+    cpu.set_failure_trap(0x6003);
+
+    let state = cpu.run(100).unwrap();
+    assert_eq!(Some(StopReason::SuccessTrap), state.stop_reason());
+    assert_eq!(0x6010, state.pc.read());
+    assert_eq!(3, state.cycles());
+
+    // nop sled walks into the failure trap.
+    let mut cpu = generate_test_cpu_with_instructions(vec![]);
+    cpu.set_success_trap(0x6010);
+    cpu.set_failure_trap(0x6003);
+
+    let state = cpu.run(100).unwrap();
+    assert_eq!(Some(StopReason::FailureTrap), state.stop_reason());
+    assert_eq!(0x6003, state.pc.read());
+    assert_eq!(6, state.cycles());
+}
+
+#[test]
+fn should_stop_run_once_an_instruction_stores_to_the_result_trap() {
+    use crate::cpu::mos6502::StopReason;
+
+    // LDA #$5a, STA $10, LDA #$00
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xa9, 0x5a, 0x85, 0x10, 0xa9, 0x00]);
+    cpu.set_result_trap(0x0010);
+
+    let state = cpu.run(100).unwrap();
+    assert_eq!(Some(StopReason::ResultTrap(0x5a)), state.stop_reason());
+    assert_eq!(0x6004, state.pc.read());
+    assert_eq!(5, state.cycles());
+    assert_eq!(0x5a, state.acc.read());
+
+    // the trap is cleared on the following run.
+    let state = state.run(2).unwrap();
+    assert_eq!(None, state.stop_reason());
+    assert_eq!(0x00, state.acc.read());
+}
+
+// Stack wrapping
+
+#[test]
+fn stack_pushes_should_wrap_within_the_stack_page_when_full() {
+    // PHA, PHP
+    let cpu = generate_test_cpu_with_instructions(vec![0x48, 0x08])
+        .with_gp_register(GpRegister::Acc, register::GeneralPurpose::with_value(0x42))
+        .with_ps_register(register::ProcessorStatus::with_value(0x00))
+        .with_sp_register(register::StackPointer::with_value(0x00));
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0xfe, state.sp.read());
+    assert_eq!(0x42, state.address_map.read(0x0100));
+    assert_eq!(0x30, state.address_map.read(0x01ff));
+}
+
+#[test]
+fn stack_pulls_should_wrap_within_the_stack_page_when_empty() {
+    // PLA, PLP
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x68, 0x28])
+        .with_sp_register(register::StackPointer::with_value(0xff));
+    cpu.address_map.write(0x0100, 0x42).unwrap();
+    cpu.address_map.write(0x0101, 0x01).unwrap();
+
+    let state = cpu.run(8).unwrap();
+    assert_eq!(0x01, state.sp.read());
+    assert_eq!(0x42, state.acc.read());
+    assert!(state.ps.carry);
+}
+
+#[test]
+fn rts_should_wrap_the_stack_pointer_when_pulling_the_return_address() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![0x60])
+        .with_sp_register(register::StackPointer::with_value(0xfe));
+    cpu.address_map.write(0x01ff, 0x0f).unwrap();
+    cpu.address_map.write(0x0100, 0x60).unwrap();
+
+    let state = cpu.run(6).unwrap();
+    assert_eq!(0x00, state.sp.read());
+    assert_eq!(0x6010, state.pc.read());
+}
+
+// Microcode execution
+
+#[test]
+fn should_apply_each_microcode_variant_to_cpu_state() {
+    use crate::cpu::{
+        mos6502::{
+            microcode::Microcode,
+            register::{ByteRegisters, ProgramStatusFlags, WordRegisters},
+        },
+        ExecuteMut,
+    };
+
+    let mut cpu = Mos6502::default()
+        .with_pc_register(register::ProgramCounter::with_value(0x6000))
+        .with_sp_register(register::StackPointer::with_value(0xff));
+
+    let steps: Vec<Vec<Microcode>> = vec![
+        vec![
+            gen_write_memory_microcode!(0x01ff, 0x42),
+            gen_dummy_write_memory_microcode!(0x01fe, 0x24),
+        ],
+        vec![
+            gen_flag_set_microcode!(ProgramStatusFlags::Carry, true),
+            gen_write_8bit_register_microcode!(ByteRegisters::Acc, 0x10),
+            gen_inc_8bit_register_microcode!(ByteRegisters::X, 2),
+            gen_dec_8bit_register_microcode!(ByteRegisters::Sp, 1),
+        ],
+        vec![
+            gen_write_16bit_register_microcode!(WordRegisters::Pc, 0x1234),
+            gen_inc_16bit_register_microcode!(WordRegisters::Pc, 3),
+            gen_dec_16bit_register_microcode!(WordRegisters::Pc, 1),
+        ],
+    ];
+
+    for mc in steps.iter().flatten() {
+        cpu.execute_mut(mc);
+    }
+
+    assert_eq!(0x42, cpu.address_map.read(0x01ff));
+    assert_eq!(0x24, cpu.address_map.read(0x01fe));
+    assert!(cpu.ps.carry);
+    assert_eq!(0x10, cpu.acc.read());
+    assert_eq!(0x02, cpu.x.read());
+    assert_eq!(0xfe, cpu.sp.read());
+    assert_eq!(0x1236, cpu.pc.read());
+}
+
+#[test]
+fn should_apply_generated_microcode_to_cpu_state() {
+    use crate::cpu::{
+        mos6502::{microcode::Microcode, Generate},
+        ExecuteMut,
+    };
+    use isa_mos6502::{addressing_mode, mnemonic, Instruction, InstructionVariant};
+
+    let mut cpu = Mos6502::default()
+        .with_pc_register(register::ProgramCounter::with_value(0x6000))
+        .with_gp_register(GpRegister::Acc, register::GeneralPurpose::with_value(0x55));
+    let op: InstructionVariant =
+        Instruction::new(mnemonic::Sta, addressing_mode::ZeroPage(0x10)).into();
+    let steps: Vec<Vec<Microcode>> = op.generate(&cpu).into();
+
+    for mc in steps.iter().flatten() {
+        cpu.execute_mut(mc);
+    }
+
+    assert_eq!(0x55, cpu.address_map.read(0x0010));
+    assert_eq!(0x6002, cpu.pc.read());
+}
+
+// Cycle budget
+
+#[test]
+fn should_run_whole_instructions_until_the_cycle_budget_is_met() {
+    let mut cpu = generate_test_cpu_with_instructions(vec![]);
+
+    assert_eq!(Ok(10), cpu.run_for(10));
+    assert_eq!(0x6005, cpu.pc.read());
+    assert_eq!(10, cpu.cycles());
+}
+
+#[test]
+fn should_execute_an_instruction_that_overshoots_the_cycle_budget_in_full() {
+    // NOP, LDA $00ff
+    let mut cpu = generate_test_cpu_with_instructions(vec![0xea, 0xad, 0xff, 0x00]);
+
+    assert_eq!(Ok(6), cpu.run_for(3));
+    assert_eq!(0x6004, cpu.pc.read());
+}
+
+#[test]
+fn should_stop_a_cycle_budget_early_on_a_trap() {
+    use crate::cpu::mos6502::StopReason;
+
+    let mut cpu = generate_test_cpu_with_instructions(vec![]);
+    cpu.set_success_trap(0x6002);
+
+    assert_eq!(Ok(4), cpu.run_for(100));
+    assert_eq!(Some(StopReason::SuccessTrap), cpu.stop_reason());
+}
+
+// Reset
+
+#[test]
+fn should_load_the_reset_vector_and_initialize_the_stack_on_reset() {
+    let cpu = Mos6502::default()
+        .register_address_space(
+            0xfffc..=0xfffd,
+            Rom::new(0xfffc, 0xfffd).load(vec![0x00, 0x80]),
+        )
+        .unwrap()
+        .reset()
+        .unwrap();
+
+    assert_eq!(0x8000, cpu.pc.read());
+    assert_eq!(0xfd, cpu.sp.read());
+    assert!(cpu.ps.interrupt_disable);
+}
@@ -0,0 +1,74 @@
+use super::{
+    disassembler::{disassemble_instruction, MAX_INSTRUCTION_LEN},
+    Mos6502,
+};
+use crate::address_map::Addressable;
+use crate::cpu::register::Register;
+use std::fmt;
+
+/// TraceLine captures the state of the cpu prior to executing the instruction
+/// at the program counter. When displayed it renders in the format of the
+/// nestest reference logs, i.e.
+///
+/// ```text
+/// 6000  A9 55     LDA #$55                        A:00 X:00 Y:00 P:24 SP:FD CYC:0
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceLine {
+    pub pc: u16,
+    pub bytes: Vec<u8>,
+    pub disassembly: String,
+    pub a: u8,
+    pub x: u8,
+    pub y: u8,
+    pub p: u8,
+    pub sp: u8,
+    pub cycles: usize,
+}
+
+impl fmt::Display for TraceLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self
+            .bytes
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        write!(
+            f,
+            "{:04X}  {:<10}{:<32}A:{:02X} X:{:02X} Y:{:02X} P:{:02X} SP:{:02X} CYC:{}",
+            self.pc, bytes, self.disassembly, self.a, self.x, self.y, self.p, self.sp, self.cycles
+        )
+    }
+}
+
+impl<B: Addressable<u16, u8>> Mos6502<B> {
+    /// Returns a trace of the instruction at the program counter along with
+    /// the current register state and cycle count.
+    pub fn trace_line(&self) -> TraceLine {
+        let pc = self.pc.read();
+        let bytes: Vec<u8> = (0..MAX_INSTRUCTION_LEN as u16)
+            .map(|offset| self.address_map.read(pc.wrapping_add(offset)))
+            .collect();
+        let (len, disassembly) = disassemble_instruction(&bytes);
+
+        // reference logs render operands in uppercase hex, i.e. `#$FF`.
+        let disassembly = match disassembly.split_once(' ') {
+            Some((mnemonic, operand)) => format!("{} {}", mnemonic, operand.to_uppercase()),
+            None => disassembly,
+        };
+
+        TraceLine {
+            pc,
+            bytes: bytes[..len].to_vec(),
+            disassembly,
+            a: self.acc.read(),
+            x: self.x.read(),
+            y: self.y.read(),
+            p: self.ps.read(),
+            sp: self.sp.read(),
+            cycles: self.cycles,
+        }
+    }
+}
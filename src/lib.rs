@@ -6,3 +6,16 @@ pub mod address_map;
 #[macro_use]
 pub mod cpu;
 pub mod prelude;
+
+pub use address_map::{
+    memory::{Memory, ReadOnly, ReadWrite},
+    AddressMap, Addressable,
+};
+pub use cpu::chip8::Chip8;
+pub use cpu::mos6502::{
+    operations::Operations,
+    register::{ByteRegisters, GpRegister, ProgramStatusFlags, WordRegisters},
+    Mos6502,
+};
+pub use cpu::{register::Register, Cpu, StepState};
+pub use isa_mos6502::{Instruction, InstructionVariant};
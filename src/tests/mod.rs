@@ -1 +1,12 @@
+use crate::cpu::Generate;
+use std::fmt::Debug;
 
+/// Asserts that an operation generates the expected output for a given cpu,
+/// providing a common assertion for opcode tests across architectures.
+pub fn assert_generates<C, T, O>(op: T, cpu: &C, expected: O)
+where
+    T: Generate<C, O>,
+    O: PartialEq + Debug,
+{
+    assert_eq!(expected, op.generate(cpu))
+}
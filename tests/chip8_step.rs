@@ -0,0 +1,29 @@
+use mainspring::cpu::chip8::register::GpRegisters;
+use mainspring::Chip8;
+
+#[test]
+fn should_step_through_a_chip8_program_one_instruction_at_a_time() {
+    // LD V0, 0x05; LD V1, 0x0a; ADD V0, V1
+    let mut cpu = Chip8::<()>::default();
+    cpu.load_program(&[0x60, 0x05, 0x61, 0x0a, 0x80, 0x14])
+        .unwrap();
+
+    assert_eq!(Ok(0x6005), cpu.step());
+    assert_eq!(0x05, cpu.read_gp_register(GpRegisters::V0));
+
+    assert_eq!(Ok(0x610a), cpu.step());
+    assert_eq!(0x0a, cpu.read_gp_register(GpRegisters::V1));
+
+    assert_eq!(Ok(0x8014), cpu.step());
+    assert_eq!(0x0f, cpu.read_gp_register(GpRegisters::V0));
+    assert_eq!(0x00, cpu.read_gp_register(GpRegisters::Vf));
+}
+
+#[test]
+fn should_return_an_error_when_stepping_an_undecodable_opcode() {
+    // 0nnn machine code routines aren't supported.
+    let mut cpu = Chip8::<()>::default();
+    cpu.load_program(&[0x01, 0x23]).unwrap();
+
+    assert!(cpu.step().is_err());
+}
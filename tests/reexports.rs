@@ -0,0 +1,15 @@
+use mainspring::{Cpu, Memory, Mos6502, ReadOnly, Register};
+
+#[test]
+fn should_step_a_cpu_using_only_crate_root_reexports() {
+    // LDA #$55
+    let rom = Memory::<ReadOnly, u16, u8>::new(0x6000, 0x6001).load(vec![0xa9, 0x55]);
+    let mut cpu = Mos6502::default()
+        .register_address_space(0x6000..=0x6001, rom)
+        .unwrap();
+    cpu.pc = cpu.pc.write(0x6000);
+
+    let state = cpu.run(2).unwrap();
+    assert_eq!(0x6002, state.pc.read());
+    assert_eq!(0x55, state.acc.read());
+}
@@ -0,0 +1,72 @@
+use mainspring::cpu::mos6502::{StepError, StopReason};
+use mainspring::{Cpu, Memory, Mos6502, ReadOnly, ReadWrite, Register};
+
+#[test]
+fn should_step_through_a_program_one_instruction_at_a_time() {
+    // LDA #$55, STA $0200, LDX $0200
+    let rom = Memory::<ReadOnly, u16, u8>::new(0x6000, 0x6007)
+        .load(vec![0xa9, 0x55, 0x8d, 0x00, 0x02, 0xae, 0x00, 0x02]);
+    let mut cpu = Mos6502::default()
+        .register_address_space(0x6000..=0x6007, rom)
+        .unwrap()
+        .register_address_space(
+            0x0200..=0x02ff,
+            Memory::<ReadWrite, u16, u8>::new(0x0200, 0x02ff),
+        )
+        .unwrap();
+    cpu.pc = cpu.pc.write(0x6000);
+
+    assert_eq!(Ok(2), cpu.step());
+    assert_eq!(0x6002, cpu.pc.read());
+    assert_eq!(0x55, cpu.acc.read());
+
+    assert_eq!(Ok(4), cpu.step());
+    assert_eq!(0x6005, cpu.pc.read());
+
+    // reading the stored value back confirms the write.
+    assert_eq!(Ok(4), cpu.step());
+    assert_eq!(0x6008, cpu.pc.read());
+    assert_eq!(0x55, cpu.x.read());
+    assert_eq!(10, cpu.cycles());
+}
+
+#[test]
+fn should_return_an_error_when_stepping_an_undecodable_opcode() {
+    let rom = Memory::<ReadOnly, u16, u8>::new(0x6000, 0x6000).load(vec![0x02]);
+    let mut cpu = Mos6502::default()
+        .register_address_space(0x6000..=0x6000, rom)
+        .unwrap();
+    cpu.pc = cpu.pc.write(0x6000);
+
+    assert_eq!(
+        Err(StepError::Decode {
+            opcode: 0x02,
+            pc: 0x6000
+        }),
+        cpu.step()
+    );
+    assert_eq!(0x6000, cpu.pc.read());
+}
+
+#[test]
+fn should_return_an_error_when_a_store_is_rejected() {
+    // STA $6000, storing into the rom holding the program.
+    let rom = Memory::<ReadOnly, u16, u8>::new(0x6000, 0x6002).load(vec![0x8d, 0x00, 0x60]);
+    let mut cpu = Mos6502::default()
+        .register_address_space(0x6000..=0x6002, rom)
+        .unwrap();
+    cpu.pc = cpu.pc.write(0x6000);
+
+    let mut stepped = cpu.clone();
+    assert!(matches!(
+        stepped.step(),
+        Err(StepError::Write { addr: 0x6000, .. })
+    ));
+
+    // run halts on the same fault rather than panicking.
+    let state = cpu.run(10).unwrap();
+    assert!(matches!(
+        state.stop_reason(),
+        Some(StopReason::Fault(StepError::Write { addr: 0x6000, .. }))
+    ));
+}